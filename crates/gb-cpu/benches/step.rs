@@ -0,0 +1,96 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::time::{Duration, Instant};
+
+use gb_cpu::Cpu;
+use gb_memory::{Cartridge, MemoryBus, Validation};
+
+// NOTE: 24.07.2025
+// The cpu does not support any jumps yet, so the benchmark rom is
+// a straight line of code which is executed from the beginning with
+// a fresh cpu every time the program counter gets close to the end
+// of the rom. The code block mixes register operations with memory
+// reads and writes to the work ram.
+
+const ROM_SIZE: usize = 0x8000;
+const CODE_END: usize = 0x7F00;
+const HEADER: std::ops::Range<usize> = 0x0100..0x0150;
+const DURATION: Duration = Duration::from_secs(3);
+
+#[rustfmt::skip]
+const BLOCK: [&[u8]; 11] = [
+    &[0x3E, 0x12], // ld a, 0x12
+    &[0x77],       // ld (hl), a
+    &[0x7E],       // ld a, (hl)
+    &[0x80],       // add a, b
+    &[0x04],       // inc b
+    &[0x22],       // ldi (hl), a
+    &[0x86],       // add a, (hl)
+    &[0x0C],       // inc c
+    &[0x47],       // ld b, a
+    &[0xA8],       // xor b
+    &[0x00],       // nop
+];
+
+fn main()
+{
+    let (rom, instructions) = build_rom();
+
+    // The header is not valid, because the logo can not be decoded
+    // into instructions supported by the cpu.
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .expect("Failed to load the benchmark rom.");
+    let mut bus = MemoryBus::new(cartridge);
+
+    let mut executed: u64 = 0;
+    let start = Instant::now();
+
+    while start.elapsed() < DURATION {
+        let mut cpu = Cpu::new();
+
+        for _ in 0..instructions {
+            cpu.step(bus.mmu());
+        }
+        executed += instructions;
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+
+    println!(
+        "executed {executed} instructions in {elapsed:.2}s: {:.2} M instructions/s",
+        executed as f64 / elapsed / 1_000_000.0
+    );
+}
+
+fn build_rom() -> (Vec<u8>, u64)
+{
+    let mut rom = vec![0x00; ROM_SIZE];
+    let mut instructions = 0;
+
+    // Point `HL` to the beginning of the work ram.
+    rom[..3].copy_from_slice(&[0x21, 0x00, 0xC0]);
+    let mut pc = 3;
+    instructions += 1;
+
+    let block_len: usize = BLOCK.iter().map(|instr| instr.len()).sum();
+
+    while pc < CODE_END {
+        // The header is executed as a series of `NOP` instructions,
+        // with the exception of the cgb flag which decodes as `ADD A, B`.
+        if pc < HEADER.end && pc + block_len > HEADER.start {
+            pc += 1;
+            instructions += 1;
+            continue;
+        }
+        for instr in BLOCK {
+            rom[pc..pc + instr.len()].copy_from_slice(instr);
+            pc += instr.len();
+            instructions += 1;
+        }
+    }
+    rom[0x143] = 0x80;
+
+    (rom, instructions)
+}
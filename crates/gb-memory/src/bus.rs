@@ -1,30 +1,178 @@
 // Copyright: (c) 2025, Wiktor Nowak
 // GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
 
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
 use crate::cartridge::builder::Cartridge;
 use crate::chunk::MemoryChunk;
 use crate::mmu::MMU;
+use crate::page_table::PageTable;
+
+// NOTE: 24.07.2025
+// The page table keeps raw pointers to the memory owned by the bus,
+// so the regions which are mapped must live on the heap and can not
+// be replaced from the outside. That is why the fields are not public,
+// the memory can still be inspected with `vram`, `wram` and `cartridge`,
+// and the cartridge changed with `cartridge_mut`.
+
+const VRAM_SIZE: usize = 0x2000;
+const WRAM_SIZE: usize = 0x2000;
 
 pub struct MemoryBus
 {
-    pub cartridge: Cartridge,
-    pub vram: MemoryChunk<0x8000, 0x9FFF>,
-    pub wram: MemoryChunk<0xC000, 0xDFFF>,
+    pub(crate) cartridge: Cartridge,
+    vram: MappedMemory<VRAM_SIZE>,
+    wram: MappedMemory<WRAM_SIZE>,
+    pub(crate) hram: MemoryChunk<0xFF80, 0x7F>,
+    pub(crate) pages: PageTable,
 }
 
 impl MemoryBus
 {
     pub fn new(cartridge: Cartridge) -> Self
     {
-        Self {
+        let mut bus = Self {
             cartridge,
-            vram: MemoryChunk::new(),
-            wram: MemoryChunk::new(),
+            vram: MappedMemory::new(),
+            wram: MappedMemory::new(),
+            hram: MemoryChunk::new(),
+            pages: PageTable::new(),
+        };
+        // SAFETY: Both regions are freed only together with the bus and
+        // are never accessed through a mutable reference.
+        unsafe {
+            bus.pages
+                .map_read_write(0x8000, bus.vram.as_ptr(), VRAM_SIZE);
+            bus.pages
+                .map_read_write(0xC000, bus.wram.as_ptr(), WRAM_SIZE);
         }
+        bus.remap_cartridge();
+
+        bus
+    }
+
+    #[inline]
+    pub fn vram(&self) -> &[u8]
+    {
+        self.vram.as_slice()
+    }
+
+    #[inline]
+    pub fn wram(&self) -> &[u8]
+    {
+        self.wram.as_slice()
+    }
+
+    #[inline]
+    pub fn cartridge(&self) -> &Cartridge
+    {
+        &self.cartridge
+    }
+
+    // Gives a mutable access to the cartridge, e.g. to save the game.
+    // The cartridge could switch the banks in the meantime, so the rom
+    // is remapped once the returned guard is dropped.
+    #[inline]
+    pub fn cartridge_mut(&mut self) -> CartridgeMut<'_>
+    {
+        CartridgeMut { bus: self }
     }
 
     pub fn mmu(&mut self) -> MMU<'_>
     {
         MMU::new(self)
     }
+
+    // Has to be called every time the cartridge could have switched
+    // the rom banks, so the fast path does not read stale data.
+    pub(crate) fn remap_cartridge(&mut self)
+    {
+        for addr in [0x0000, 0x4000] {
+            match self.cartridge.mapped_rom(addr) {
+                Some(bank) => self.pages.map_read_only(addr, bank),
+                None => self.pages.unmap(addr, 0x4000),
+            }
+        }
+    }
+}
+
+// Memory mapped into the page table. Unlike a `Box`, the pointer is not
+// treated as unique when the bus is moved, so the pointers derived from
+// it and kept by the page table stay valid.
+struct MappedMemory<const SIZE: usize>
+{
+    data: NonNull<[u8; SIZE]>,
+}
+
+impl<const SIZE: usize> MappedMemory<SIZE>
+{
+    fn new() -> Self
+    {
+        let data = Box::into_raw(Box::new([0x00; SIZE]));
+
+        // SAFETY: `Box::into_raw` never returns a null pointer.
+        Self {
+            data: unsafe { NonNull::new_unchecked(data) },
+        }
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *mut u8
+    {
+        self.data.as_ptr().cast()
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[u8]
+    {
+        // SAFETY: The memory is written only through the page table,
+        // which requires a mutable borrow of the bus, so it can not
+        // change while the slice is alive.
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<const SIZE: usize> Drop for MappedMemory<SIZE>
+{
+    fn drop(&mut self)
+    {
+        // SAFETY: The pointer comes from `Box::into_raw` and is freed
+        // only once.
+        drop(unsafe { Box::from_raw(self.data.as_ptr()) });
+    }
+}
+
+// SAFETY: The memory is owned exclusively, same as with a `Box`.
+unsafe impl<const SIZE: usize> Send for MappedMemory<SIZE> {}
+
+pub struct CartridgeMut<'a>
+{
+    bus: &'a mut MemoryBus,
+}
+
+impl Deref for CartridgeMut<'_>
+{
+    type Target = Cartridge;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.bus.cartridge
+    }
+}
+
+impl DerefMut for CartridgeMut<'_>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        &mut self.bus.cartridge
+    }
+}
+
+impl Drop for CartridgeMut<'_>
+{
+    fn drop(&mut self)
+    {
+        self.bus.remap_cartridge();
+    }
 }
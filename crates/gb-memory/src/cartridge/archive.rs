@@ -0,0 +1,190 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use miniz_oxide::inflate::decompress_to_vec_with_limit;
+
+use crate::cartridge::errors::CartridgeError;
+use crate::cartridge::hash::crc32;
+
+const GZIP_MAGIC: &[u8] = &[0x1F, 0x8B];
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+
+const ZIP_LOCAL_HEADER: u32 = 0x04034B50;
+const ZIP_CENTRAL_HEADER: u32 = 0x02014B50;
+const ZIP_END_OF_DIRECTORY: u32 = 0x06054B50;
+const ZIP_END_OF_DIRECTORY_SIZE: usize = 22;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATE: u16 = 8;
+
+// Optional fields of the gzip header.
+const GZIP_FLAG_HEADER_CRC: u8 = 0x02;
+const GZIP_FLAG_EXTRA: u8 = 0x04;
+const GZIP_FLAG_NAME: u8 = 0x08;
+const GZIP_FLAG_COMMENT: u8 = 0x10;
+
+// Anything larger is certainly not a rom, this protects us from
+// decompression bombs.
+const MAX_UNPACKED_SIZE: usize = 0x4000000;
+
+const ROM_EXTENSIONS: [&str; 2] = [".gb", ".gbc"];
+
+// Returns the rom stored in the archive or the data itself if it is not
+// compressed. If no entry is given, the first rom in a zip is picked.
+pub fn unpack(data: Vec<u8>, entry: Option<&str>) -> Result<Vec<u8>, CartridgeError>
+{
+    match data {
+        _ if data.starts_with(ZIP_MAGIC) => unpack_zip(&data, entry),
+        _ if data.starts_with(GZIP_MAGIC) => unpack_gzip(&data),
+        _ => Ok(data),
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, CartridgeError>
+{
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(CartridgeError::Archive("unexpected end of archive"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, CartridgeError>
+{
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or(CartridgeError::Archive("unexpected end of archive"))
+}
+
+fn inflate(data: &[u8], expected_crc: u32) -> Result<Vec<u8>, CartridgeError>
+{
+    let unpacked = decompress_to_vec_with_limit(data, MAX_UNPACKED_SIZE)
+        .map_err(|_| CartridgeError::Archive("corrupted compressed data"))?;
+
+    match crc32(&unpacked) == expected_crc {
+        true => Ok(unpacked),
+        false => Err(CartridgeError::Archive("checksum mismatch")),
+    }
+}
+
+struct ZipEntry<'a>
+{
+    name: &'a str,
+    method: u16,
+    crc: u32,
+    compressed_size: usize,
+    header_offset: usize,
+}
+
+// The central directory at the end of the archive is the only reliable
+// source of the entry sizes, the local headers may leave them empty.
+fn read_zip_directory(data: &[u8]) -> Result<Vec<ZipEntry<'_>>, CartridgeError>
+{
+    let end = (0..=data.len().saturating_sub(ZIP_END_OF_DIRECTORY_SIZE))
+        .rev()
+        .find(|offset| read_u32(data, *offset).is_ok_and(|sig| sig == ZIP_END_OF_DIRECTORY))
+        .ok_or(CartridgeError::Archive("missing zip central directory"))?;
+
+    let count = read_u16(data, end + 10)? as usize;
+    let mut offset = read_u32(data, end + 16)? as usize;
+    let mut entries = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        if read_u32(data, offset)? != ZIP_CENTRAL_HEADER {
+            return Err(CartridgeError::Archive("corrupted zip central directory"));
+        }
+        let name_len = read_u16(data, offset + 28)? as usize;
+        let extra_len = read_u16(data, offset + 30)? as usize;
+        let comment_len = read_u16(data, offset + 32)? as usize;
+
+        let name = data
+            .get(offset + 46..offset + 46 + name_len)
+            .and_then(|name| str::from_utf8(name).ok())
+            .ok_or(CartridgeError::Archive("invalid zip entry name"))?;
+
+        entries.push(ZipEntry {
+            name,
+            method: read_u16(data, offset + 10)?,
+            crc: read_u32(data, offset + 16)?,
+            compressed_size: read_u32(data, offset + 20)? as usize,
+            header_offset: read_u32(data, offset + 42)? as usize,
+        });
+        offset += 46 + name_len + extra_len + comment_len;
+    }
+    Ok(entries)
+}
+
+fn unpack_zip(data: &[u8], entry: Option<&str>) -> Result<Vec<u8>, CartridgeError>
+{
+    let entries = read_zip_directory(data)?;
+
+    let found = match entry {
+        Some(name) => entries
+            .iter()
+            .find(|entry| entry.name == name || entry.name.rsplit('/').next() == Some(name))
+            .ok_or_else(|| CartridgeError::ArchiveEntryNotFound(name.to_string()))?,
+        None => entries
+            .iter()
+            .find(|entry| {
+                let name = entry.name.to_ascii_lowercase();
+                ROM_EXTENSIONS.iter().any(|ext| name.ends_with(ext))
+            })
+            .ok_or(CartridgeError::Archive("no rom found in the archive"))?,
+    };
+    let offset = found.header_offset;
+
+    if read_u32(data, offset)? != ZIP_LOCAL_HEADER {
+        return Err(CartridgeError::Archive("corrupted zip entry"));
+    }
+    let name_len = read_u16(data, offset + 26)? as usize;
+    let extra_len = read_u16(data, offset + 28)? as usize;
+    let start = offset + 30 + name_len + extra_len;
+
+    let packed = data
+        .get(start..start + found.compressed_size)
+        .ok_or(CartridgeError::Archive("unexpected end of archive"))?;
+
+    match found.method {
+        METHOD_STORED if crc32(packed) == found.crc => Ok(packed.to_vec()),
+        METHOD_STORED => Err(CartridgeError::Archive("checksum mismatch")),
+        METHOD_DEFLATE => inflate(packed, found.crc),
+        _ => Err(CartridgeError::Archive(
+            "unsupported zip compression method",
+        )),
+    }
+}
+
+fn unpack_gzip(data: &[u8]) -> Result<Vec<u8>, CartridgeError>
+{
+    let method = *data.get(2).unwrap_or(&0);
+    let flags = *data.get(3).unwrap_or(&0);
+
+    if method != METHOD_DEFLATE as u8 {
+        return Err(CartridgeError::Archive(
+            "unsupported gzip compression method",
+        ));
+    }
+    let mut offset = 10;
+
+    if flags & GZIP_FLAG_EXTRA != 0 {
+        offset += 2 + read_u16(data, offset)? as usize;
+    }
+    for flag in [GZIP_FLAG_NAME, GZIP_FLAG_COMMENT] {
+        if flags & flag != 0 {
+            let len = data
+                .get(offset..)
+                .and_then(|rest| rest.iter().position(|byte| *byte == 0))
+                .ok_or(CartridgeError::Archive("unexpected end of archive"))?;
+            offset += len + 1;
+        }
+    }
+    if flags & GZIP_FLAG_HEADER_CRC != 0 {
+        offset += 2;
+    }
+    // The trailer holds the checksum and the size of unpacked data.
+    let trailer = data
+        .len()
+        .checked_sub(8)
+        .filter(|trailer| *trailer >= offset)
+        .ok_or(CartridgeError::Archive("unexpected end of archive"))?;
+
+    inflate(&data[offset..trailer], read_u32(data, trailer)?)
+}
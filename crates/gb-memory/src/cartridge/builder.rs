@@ -3,35 +3,405 @@
 
 use std::fs::File;
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::MemoryAccess;
-use crate::cartridge::errors::CartridgeError;
-use crate::cartridge::header::{CartridgeType, Header};
+use crate::cartridge::camera::CameraImage;
+use crate::cartridge::database::{DatEntry, RomDatabase};
+use crate::cartridge::errors::{CartridgeError, CartridgeWarning};
+use crate::cartridge::hash::RomHashes;
+use crate::cartridge::header::{CgbFlag, Header, Mapper};
+use crate::cartridge::infrared::InfraredChannel;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{MAX_ROM_BANKS, ROM_BANK_SIZE};
+use crate::cartridge::mbc::huc1::HuC1;
+use crate::cartridge::mbc::huc3::HuC3;
 use crate::cartridge::mbc::mbc0::Mbc0;
 use crate::cartridge::mbc::mbc1::Mbc1;
-
-// TODO: Detect which cartridge has a persistent `RAM` and save the state to the save file.
-// TODO: Validate the checksum.
+use crate::cartridge::mbc::mbc2::Mbc2;
+use crate::cartridge::mbc::mbc3::Mbc3;
+use crate::cartridge::mbc::mbc5::Mbc5;
+use crate::cartridge::mbc::mbc6::Mbc6;
+use crate::cartridge::mbc::mbc7::Mbc7;
+use crate::cartridge::mbc::mmm01::Mmm01;
+use crate::cartridge::mbc::pocket_camera::PocketCamera;
+use crate::cartridge::mbc::sachen::Sachen;
+use crate::cartridge::mbc::tama5::Tama5;
+use crate::cartridge::mbc::wisdom_tree::WisdomTree;
+use crate::cartridge::options::CartridgeOptions;
+use crate::cartridge::patch::Patch;
+use crate::cartridge::rtc::{Clock, SystemClock};
+use crate::cartridge::save::SaveFile;
+use crate::cartridge::validation::{Validation, ValidationReport};
+use crate::cartridge::{archive, detection};
+use crate::consts::rom;
 
 pub struct Cartridge
 {
-    mbc: Box<dyn MemoryAccess>,
+    header: Header,
+    // Either declared by the header, detected or forced by the user.
+    mapper: Mapper,
+    hashes: RomHashes,
+    validation: ValidationReport,
+    warnings: Vec<CartridgeWarning>,
+    // Either declared by the header or forced by the user.
+    battery: bool,
+    // Present only for the cartridges with a battery.
+    save: Option<SaveFile>,
+    mbc: Box<dyn Mbc>,
 }
 
-impl Cartridge
+// Loads the cartridge with the settings which can't be derived from the
+// rom alone. Everything which is not set explicitly is taken from the
+// header, the save file is placed next to the rom file by default.
+#[derive(Debug, Default, Clone)]
+pub struct CartridgeBuilder
 {
-    pub fn from_file<P>(path: P) -> Result<Self, CartridgeError>
+    validation: Validation,
+    options: CartridgeOptions,
+    save_path: Option<PathBuf>,
+    archive_entry: Option<String>,
+    patches: Vec<Patch>,
+    // Shared, so the builder can still be cloned.
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl CartridgeBuilder
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    pub fn validation(mut self, validation: Validation) -> Self
+    {
+        self.validation = validation;
+        self
+    }
+
+    // Replaces all of the overrides set so far.
+    pub fn options(mut self, options: CartridgeOptions) -> Self
+    {
+        self.options = options;
+        self
+    }
+
+    // Useful for homebrew and bootlegs with a wrong cartridge type.
+    pub fn mapper(mut self, mapper: Mapper) -> Self
+    {
+        self.options.mapper = Some(mapper);
+        self
+    }
+
+    pub fn rom_banks(mut self, rom_banks: usize) -> Self
+    {
+        self.options.rom_banks = Some(rom_banks);
+        self
+    }
+
+    pub fn ram_banks(mut self, ram_banks: usize) -> Self
+    {
+        self.options.ram_banks = Some(ram_banks);
+        self
+    }
+
+    pub fn battery(mut self, battery: bool) -> Self
+    {
+        self.options.battery = Some(battery);
+        self
+    }
+
+    pub fn cgb(mut self, cgb: CgbFlag) -> Self
+    {
+        self.options.cgb = Some(cgb);
+        self
+    }
+
+    pub fn save_path<P>(mut self, path: P) -> Self
     where
         P: Into<PathBuf>,
+    {
+        self.save_path = Some(path.into());
+        self
+    }
+
+    // Picks the rom from an archive with more than one entry.
+    pub fn archive_entry<S>(mut self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.archive_entry = Some(name.into());
+        self
+    }
+
+    // Patches are applied in the order they were added, before the
+    // header is parsed.
+    pub fn patch(mut self, patch: Patch) -> Self
+    {
+        self.patches.push(patch);
+        self
+    }
+
+    // Replaces the system time used by the real-time clock of the MBC3,
+    // MBC30, HuC3 and TAMA5 cartridges.
+    pub fn clock(mut self, clock: Box<dyn Clock>) -> Self
+    {
+        self.clock = Some(Arc::from(clock));
+        self
+    }
+
+    pub fn from_file<P>(mut self, path: P) -> Result<Cartridge, CartridgeError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        if self.save_path.is_none() {
+            self.save_path = Some(SaveFile::next_to(path).path().to_path_buf());
+        }
+        self.from_reader(File::open(path)?)
+    }
+
+    pub fn from_reader<R>(self, mut reader: R) -> Result<Cartridge, CartridgeError>
+    where
+        R: Read,
     {
         let mut buf = Vec::new();
-        File::open(path.into())?.read_to_end(&mut buf)?;
+        reader.read_to_end(&mut buf)?;
+
+        self.from_bytes(buf)
+    }
+
+    // Without a save path the battery backed ram lives only in memory.
+    // Zip and gzip archives are unpacked transparently.
+    pub fn from_bytes(self, rom: Vec<u8>) -> Result<Cartridge, CartridgeError>
+    {
+        let mut rom = archive::unpack(rom, self.archive_entry.as_deref())?;
+
+        for patch in &self.patches {
+            rom = patch.apply(&rom)?;
+        }
+        let hashes = RomHashes::new(&rom);
+        let view = detection::header_view(&rom);
+        let size_code = view.get(rom::ROM_SIZE).copied();
+
+        let mut header = Header::new(&view)?;
+        let mut report = header.validate(&view);
+
+        // The view of a multicart holds only the menu.
+        if Header::offset(&rom) > 0 {
+            report.global_checksum.computed = Header::compute_image_checksum(&rom);
+        }
+        let mut warnings = report.warnings();
+        drop(view);
+
+        if self.validation == Validation::Strict
+            && let Some(fatal) = warnings.iter().find(|warning| warning.is_fatal())
+        {
+            return Err(CartridgeError::Rejected(fatal.clone()));
+        }
+        if let Some(code) = size_code
+            && Header::rom_banks_from_code(code).is_none()
+        {
+            warnings.push(CartridgeWarning::UnknownRomSize(code));
+        }
+        match self.options.rom_banks {
+            Some(banks) => force_rom_size(&mut header, &mut rom, banks)?,
+            None => fit_rom_size(&mut header, &mut rom, &mut warnings)?,
+        }
+        self.options.apply(&mut header);
+
+        let mapper = self
+            .options
+            .mapper
+            .unwrap_or_else(|| detection::detect_mapper(&header, &rom));
+        let battery = self.options.has_battery(&header);
+
+        let clock: Box<dyn Clock> = match self.clock {
+            Some(clock) => Box::new(clock),
+            None => Box::new(SystemClock),
+        };
+        let mut mbc = mbc_factory(&header, mapper, rom, clock);
+        let save = match (self.save_path, battery) {
+            (Some(path), true) => Some(load_save(SaveFile::new(path), mbc.as_mut())?),
+            _ => None,
+        };
+
+        Ok(Cartridge {
+            header,
+            mapper,
+            hashes,
+            validation: report,
+            warnings,
+            battery,
+            save,
+            mbc,
+        })
+    }
+}
+
+impl Cartridge
+{
+    pub fn builder() -> CartridgeBuilder
+    {
+        CartridgeBuilder::new()
+    }
+
+    pub fn from_file<P>(path: P) -> Result<Self, CartridgeError>
+    where
+        P: AsRef<Path>,
+    {
+        CartridgeBuilder::new().from_file(path)
+    }
+
+    pub fn from_file_with_validation<P>(
+        path: P, validation: Validation,
+    ) -> Result<Self, CartridgeError>
+    where
+        P: AsRef<Path>,
+    {
+        CartridgeBuilder::new()
+            .validation(validation)
+            .from_file(path)
+    }
+
+    pub fn from_reader<R>(reader: R) -> Result<Self, CartridgeError>
+    where
+        R: Read,
+    {
+        CartridgeBuilder::new().from_reader(reader)
+    }
+
+    pub fn from_bytes(rom: Vec<u8>) -> Result<Self, CartridgeError>
+    {
+        CartridgeBuilder::new().from_bytes(rom)
+    }
+
+    #[inline]
+    pub fn header(&self) -> &Header
+    {
+        &self.header
+    }
+
+    #[inline]
+    pub fn mapper(&self) -> Mapper
+    {
+        self.mapper
+    }
+
+    // Hashes of the image after unpacking and patching.
+    #[inline]
+    pub fn hashes(&self) -> &RomHashes
+    {
+        &self.hashes
+    }
+
+    // Looks up the dump in the database, the result can be used to
+    // apply per-game settings.
+    #[inline]
+    pub fn identify<'a>(&self, database: &'a RomDatabase) -> Option<&'a DatEntry>
+    {
+        database.identify(&self.hashes)
+    }
+
+    #[inline]
+    pub fn validation(&self) -> &ValidationReport
+    {
+        &self.validation
+    }
+
+    #[inline]
+    pub fn warnings(&self) -> &[CartridgeWarning]
+    {
+        &self.warnings
+    }
+
+    #[inline]
+    pub fn has_battery(&self) -> bool
+    {
+        self.battery
+    }
+
+    #[inline]
+    pub fn save_path(&self) -> Option<&Path>
+    {
+        self.save.as_ref().map(SaveFile::path)
+    }
+
+    // Writes the battery backed ram to the save file, if anything
+    // changed since the last time it was saved. Cartridges with a clock
+    // are always written, so the timestamp in the footer is refreshed
+    // even if the game only latched the time. Marking the save dirty on
+    // every latch would keep postponing `autosave` in the games which
+    // latch the clock every frame.
+    pub fn save(&mut self) -> Result<(), CartridgeError>
+    {
+        if let Some(save) = &mut self.save
+            && (save.is_dirty() || self.header.cartridge_type.has_rtc())
+        {
+            let mut data = self.mbc.dump_ram();
+            data.extend(self.mbc.dump_rtc());
+
+            save.write(&data)?;
+        }
+        Ok(())
+    }
+
+    // Should be called periodically by the front-end (e.g. once per
+    // frame). The ram is saved only once the game stopped writing to
+    // it for a while, so a save in progress is never persisted half
+    // way through.
+    pub fn autosave(&mut self) -> Result<(), CartridgeError>
+    {
+        match self.save.as_mut().is_some_and(SaveFile::is_settled) {
+            true => self.save(),
+            false => Ok(()),
+        }
+    }
+
+    // The motor is switched by the game, so the front-end should poll
+    // this regularly (e.g. once per frame) to drive the force feedback.
+    #[inline]
+    pub fn is_rumbling(&self) -> bool
+    {
+        self.mbc.is_rumbling()
+    }
 
-        let header = Header::new(&buf)?;
-        let mbc = mbc_factory(&header, buf);
+    // Should be updated by the front-end whenever the tilt changes, the
+    // values are in g and usually stay within [-1.0, 1.0].
+    #[inline]
+    pub fn set_tilt(&mut self, x: f32, y: f32)
+    {
+        self.mbc.set_tilt(x, y);
+    }
+
+    // Picture which will be taken by the Pocket Camera, e.g. the last
+    // frame from the webcam. Ignored by the other cartridges.
+    #[inline]
+    pub fn set_camera_image(&mut self, image: CameraImage)
+    {
+        self.mbc.set_camera_image(image);
+    }
+
+    // Connects the infrared port of the HuC1 and HuC3 cartridges, e.g.
+    // to one end of `InfraredLink` shared with another instance.
+    #[inline]
+    pub fn connect_infrared(&mut self, channel: Box<dyn InfraredChannel>)
+    {
+        self.mbc.connect_infrared(channel);
+    }
+
+    #[inline]
+    pub(crate) fn is_bank_write(&self, addr: u16) -> bool
+    {
+        self.mbc.is_bank_write(addr)
+    }
 
-        Ok(Self { mbc })
+    #[inline]
+    pub(crate) fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        self.mbc.mapped_rom(addr)
     }
 }
 
@@ -45,13 +415,117 @@ impl MemoryAccess for Cartridge
     fn write_byte(&mut self, addr: u16, val: u8)
     {
         self.mbc.write_byte(addr, val);
+
+        if let Some(save) = &mut self.save
+            && self.mbc.is_save_write(addr)
+        {
+            save.mark_dirty();
+        }
+    }
+}
+
+impl Drop for Cartridge
+{
+    fn drop(&mut self)
+    {
+        // There is no way to report the error at this point, the
+        // front-end should call `save` itself if it cares about it.
+        let _ = self.save();
     }
 }
 
-fn mbc_factory(header: &Header, rom: Vec<u8>) -> Box<dyn MemoryAccess>
+fn load_save(save: SaveFile, mbc: &mut dyn Mbc) -> Result<SaveFile, CartridgeError>
+{
+    if let Some(data) = save.read()? {
+        // The clock state, if present, is stored right after the ram.
+        let ram_len = mbc.dump_ram().len().min(data.len());
+        let (ram, rtc) = data.split_at(ram_len);
+
+        mbc.load_ram(ram);
+
+        if !rtc.is_empty() {
+            mbc.load_rtc(rtc);
+        }
+    }
+    Ok(save)
+}
+
+// Makes sure that the image is exactly as large as the number of banks
+// in the header. Truncated images are padded with the value of the open
+// bus and overdumps, which only repeat the rom or contain the filler,
+// are trimmed. If there is real data past the declared size, the header
+// is most likely wrong and the size of the image is trusted instead.
+fn fit_rom_size(
+    header: &mut Header, rom: &mut Vec<u8>, warnings: &mut Vec<CartridgeWarning>,
+) -> Result<(), CartridgeError>
+{
+    let actual = rom.len();
+    let mut expected = header.rom_banks * ROM_BANK_SIZE;
+
+    if actual > expected {
+        let extra = &rom[expected..];
+
+        let is_mirror = extra
+            .chunks(expected)
+            .all(|chunk| chunk == &rom[..chunk.len()]);
+        let is_filler = extra.iter().all(|byte| *byte == extra[0]);
+
+        match is_mirror || is_filler {
+            true => warnings.push(CartridgeWarning::RomOverdumped { expected, actual }),
+            // Rounding up to the next size the header can declare is
+            // not a truncation, the image is just padded.
+            false => {
+                warnings.push(CartridgeWarning::RomLargerThanDeclared { expected, actual });
+
+                header.rom_banks = Header::rom_banks_from_len(actual);
+                expected = header.rom_banks * ROM_BANK_SIZE;
+            }
+        }
+    } else if actual < expected {
+        warnings.push(CartridgeWarning::RomTruncated { expected, actual });
+    }
+    if header.rom_banks > MAX_ROM_BANKS {
+        return Err(CartridgeError::RomTooLarge(actual));
+    }
+    rom.resize(expected, 0xFF);
+
+    Ok(())
+}
+
+// The size forced by the user is trusted blindly, the image is padded
+// with the value of the open bus or trimmed to match it. Every mapper
+// expects at least the two banks mapped at boot.
+fn force_rom_size(
+    header: &mut Header, rom: &mut Vec<u8>, banks: usize,
+) -> Result<(), CartridgeError>
+{
+    if banks > MAX_ROM_BANKS {
+        return Err(CartridgeError::RomTooLarge(banks * ROM_BANK_SIZE));
+    }
+    header.rom_banks = banks.max(2);
+    rom.resize(header.rom_banks * ROM_BANK_SIZE, 0xFF);
+
+    Ok(())
+}
+
+fn mbc_factory(header: &Header, mapper: Mapper, rom: Vec<u8>, clock: Box<dyn Clock>)
+-> Box<dyn Mbc>
 {
-    match header.cartridge_type {
-        CartridgeType::Mbc0 => Box::new(Mbc0::new(rom)),
-        _ => Box::new(Mbc1::new(header, rom)),
+    match mapper {
+        Mapper::None => Box::new(Mbc0::new(header, rom)),
+        Mapper::Mbc1 => Box::new(Mbc1::new(header, rom)),
+        Mapper::Mbc2 => Box::new(Mbc2::new(rom)),
+        Mapper::Mbc3 => Box::new(Mbc3::new(header, rom, clock)),
+        Mapper::Mbc30 => Box::new(Mbc3::mbc30(header, rom, clock)),
+        Mapper::Mmm01 => Box::new(Mmm01::new(header, rom)),
+        Mapper::Mbc5 => Box::new(Mbc5::new(header, rom)),
+        Mapper::Mbc6 => Box::new(Mbc6::new(rom)),
+        Mapper::Mbc7 => Box::new(Mbc7::new(rom)),
+        Mapper::PocketCamera => Box::new(PocketCamera::new(rom)),
+        Mapper::Tama5 => Box::new(Tama5::new(rom, clock)),
+        Mapper::HuC3 => Box::new(HuC3::new(header, rom, clock)),
+        Mapper::HuC1 => Box::new(HuC1::new(header, rom)),
+        Mapper::WisdomTree => Box::new(WisdomTree::new(rom)),
+        Mapper::SachenMmc1 | Mapper::SachenMmc2 => Box::new(Sachen::new(rom)),
     }
 }
@@ -0,0 +1,309 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fs;
+use std::path::Path;
+
+use crate::cartridge::errors::CartridgeError;
+
+// Resolution of the picture stored by the camera, the sensor itself
+// has a few more rows which are never visible.
+pub const SENSOR_WIDTH: usize = 128;
+pub const SENSOR_HEIGHT: usize = 112;
+
+// Number of the sensor registers, mirrored over 0xA000 - 0xA07F.
+pub const SENSOR_REGISTERS: usize = 0x36;
+
+// The picture is stored as 16x14 tiles in the 2bpp format, starting
+// from 0xA100 of the first ram bank.
+pub const IMAGE_OFFSET: usize = 0x100;
+pub const IMAGE_SIZE: usize = SENSOR_WIDTH * SENSOR_HEIGHT / 4;
+
+const START_CAPTURE_BIT: u8 = 0x01;
+const INVERT_BIT: u8 = 0x08;
+const DITHER_MATRIX_START: usize = 0x06;
+
+// The 2-D edge enhancement is selected with both bits of `VH` set.
+const EDGE_MODE_MASK: u8 = 0x60;
+const EDGE_RATIOS: [f32; 8] = [0.5, 0.75, 1.0, 1.25, 2.0, 3.0, 4.0, 5.0];
+
+// Exposure time which keeps the brightness of the host image intact.
+const NEUTRAL_EXPOSURE: u32 = 0x1000;
+
+// The picture is scaled down to the sensor anyway, anything larger than
+// a frame of a typical webcam is most likely a broken file.
+pub const MAX_IMAGE_SIDE: usize = 4096;
+
+// Greyscale picture supplied by the front-end, e.g. a frame from the
+// webcam. It is scaled to the resolution of the sensor on capture.
+#[derive(Debug, Clone)]
+pub struct CameraImage
+{
+    width: usize,
+    height: usize,
+    // One byte per pixel, 0x00 is black and 0xFF is white.
+    pixels: Vec<u8>,
+}
+
+impl CameraImage
+{
+    pub fn new(width: usize, height: usize, pixels: Vec<u8>) -> Result<Self, CartridgeError>
+    {
+        if pixels.len() != pixel_count(width, height)? {
+            return Err(CartridgeError::CameraImage(
+                "size does not match the pixels",
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn from_file<P>(path: P) -> Result<Self, CartridgeError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_pgm(&fs::read(path)?)
+    }
+
+    // Supports both the binary (P5) and the plain (P2) variants of the
+    // `PGM` format.
+    pub fn from_pgm(data: &[u8]) -> Result<Self, CartridgeError>
+    {
+        let invalid = CartridgeError::CameraImage("invalid pgm file");
+
+        let binary = match data.get(..2) {
+            Some(b"P5") => true,
+            Some(b"P2") => false,
+            _ => return Err(invalid),
+        };
+        let mut pos = 2;
+        let mut fields = [0; 3];
+
+        for field in &mut fields {
+            *field = read_pgm_number(data, &mut pos)
+                .ok_or(CartridgeError::CameraImage("invalid pgm header"))?;
+        }
+        let [width, height, max] = fields;
+
+        if max == 0 || max > 0xFFFF {
+            return Err(CartridgeError::CameraImage("invalid pgm header"));
+        }
+        let count = pixel_count(width, height)?;
+        let wide = max > 0xFF;
+        let scale = |sample: usize| (sample.min(max) * 0xFF / max) as u8;
+
+        let truncated = CartridgeError::CameraImage("truncated pgm file");
+        // Exactly one whitespace separates the header from the data.
+        let binary_data = |len: usize| data.get(pos + 1..).and_then(|data| data.get(..len));
+
+        let pixels = match binary {
+            true if wide => binary_data(count * 2)
+                .ok_or(truncated)?
+                .chunks_exact(2)
+                .map(|pair| scale(u16::from_be_bytes([pair[0], pair[1]]) as usize))
+                .collect(),
+            true => binary_data(count)
+                .ok_or(truncated)?
+                .iter()
+                .map(|sample| scale(*sample as usize))
+                .collect(),
+            false => (0..count)
+                .map(|_| read_pgm_number(data, &mut pos).map(scale))
+                .collect::<Option<_>>()
+                .ok_or(truncated)?,
+        };
+        Self::new(width, height, pixels)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize
+    {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize
+    {
+        self.height
+    }
+
+    // Nearest neighbour scaling to the resolution of the sensor,
+    // coordinates outside of the picture are clamped to the edges.
+    fn sample(&self, x: isize, y: isize) -> f32
+    {
+        let x = x.clamp(0, SENSOR_WIDTH as isize - 1) as usize;
+        let y = y.clamp(0, SENSOR_HEIGHT as isize - 1) as usize;
+
+        let src_x = x * self.width / SENSOR_WIDTH;
+        let src_y = y * self.height / SENSOR_HEIGHT;
+
+        self.pixels[src_y * self.width + src_x] as f32
+    }
+}
+
+// Makes sure the size is sane before anything is allocated for it.
+fn pixel_count(width: usize, height: usize) -> Result<usize, CartridgeError>
+{
+    let valid = |side: usize| (1..=MAX_IMAGE_SIDE).contains(&side);
+
+    width
+        .checked_mul(height)
+        .filter(|_| valid(width) && valid(height))
+        .ok_or(CartridgeError::CameraImage("unsupported image size"))
+}
+
+// Skips the whitespace and comments, then parses the decimal number.
+fn read_pgm_number(data: &[u8], pos: &mut usize) -> Option<usize>
+{
+    loop {
+        match data.get(*pos)? {
+            byte if byte.is_ascii_whitespace() => *pos += 1,
+            b'#' => {
+                while *data.get(*pos)? != b'\n' {
+                    *pos += 1;
+                }
+            }
+            _ => break,
+        }
+    }
+    let start = *pos;
+
+    while data.get(*pos).is_some_and(u8::is_ascii_digit) {
+        *pos += 1;
+    }
+    str::from_utf8(&data[start..*pos]).ok()?.parse().ok()
+}
+
+// Registers of the `M64282FP` artificial retina, the sensor of the
+// camera, together with the processing done on the captured picture.
+#[derive(Debug)]
+pub struct Sensor
+{
+    registers: [u8; SENSOR_REGISTERS],
+    image: Option<CameraImage>,
+}
+
+impl Sensor
+{
+    pub fn new() -> Self
+    {
+        Self {
+            registers: [0x00; SENSOR_REGISTERS],
+            image: None,
+        }
+    }
+
+    #[inline]
+    pub fn set_image(&mut self, image: CameraImage)
+    {
+        self.image = Some(image);
+    }
+
+    // Only the first register can be read, bit 0 is set while the
+    // capture is in progress.
+    pub fn read(&self, addr: u16) -> u8
+    {
+        match addr & 0x7F {
+            0x00 => self.registers[0] & 0x07,
+            _ => 0x00,
+        }
+    }
+
+    pub fn write(&mut self, addr: u16, val: u8)
+    {
+        let reg = (addr & 0x7F) as usize;
+
+        if reg < SENSOR_REGISTERS {
+            self.registers[reg] = val;
+        }
+    }
+
+    #[inline]
+    pub fn is_busy(&self) -> bool
+    {
+        self.registers[0] & START_CAPTURE_BIT != 0
+    }
+
+    // NOTE: 19.10.2025
+    // The real capture takes a few frames, depending on the exposure.
+    // The mapper does not know the time, so the picture is taken
+    // at once and the busy flag is cleared before the game can see it.
+    // Only the 2-D edge enhancement mode is emulated, the other modes
+    // of the sensor produce the plain picture, and the gain is ignored.
+    pub fn capture(&mut self) -> [u8; IMAGE_SIZE]
+    {
+        self.registers[0] &= !START_CAPTURE_BIT;
+
+        let mut tiles = [0x00; IMAGE_SIZE];
+
+        for y in 0..SENSOR_HEIGHT {
+            for x in 0..SENSOR_WIDTH {
+                let color = self.dither(x, y, self.pixel(x as isize, y as isize));
+
+                let tile = (y / 8) * (SENSOR_WIDTH / 8) + x / 8;
+                let offset = tile * 16 + (y % 8) * 2;
+                let bit = 0x80 >> (x % 8);
+
+                if color & 0x01 != 0 {
+                    tiles[offset] |= bit;
+                }
+                if color & 0x02 != 0 {
+                    tiles[offset + 1] |= bit;
+                }
+            }
+        }
+        tiles
+    }
+
+    // Brightness of the pixel after the exposure and the edge
+    // enhancement, in the range [0, 255].
+    fn pixel(&self, x: isize, y: isize) -> u8
+    {
+        let exposure = u16::from_be_bytes([self.registers[2], self.registers[3]]) as f32;
+        let invert = self.registers[4] & INVERT_BIT != 0;
+
+        let raw = |x: isize, y: isize| {
+            let value = match &self.image {
+                Some(image) => image.sample(x, y),
+                None => 0x80 as f32,
+            };
+            match invert {
+                true => 255.0 - value,
+                false => value,
+            }
+        };
+        let mut value = raw(x, y);
+
+        if self.registers[1] & EDGE_MODE_MASK == EDGE_MODE_MASK {
+            let ratio = EDGE_RATIOS[((self.registers[4] >> 4) & 0x07) as usize];
+            let neighbours = raw(x - 1, y) + raw(x + 1, y) + raw(x, y - 1) + raw(x, y + 1);
+
+            value += (4.0 * value - neighbours) * ratio;
+        }
+        (value * exposure / NEUTRAL_EXPOSURE as f32).clamp(0.0, 255.0) as u8
+    }
+
+    // Every pixel is compared against the 3 thresholds of the 4x4
+    // matrix cell it falls into, the darkest color is 3.
+    fn dither(&self, x: usize, y: usize, value: u8) -> u8
+    {
+        let cell = DITHER_MATRIX_START + ((y % 4) * 4 + x % 4) * 3;
+        let thresholds = &self.registers[cell..cell + 3];
+
+        match thresholds.iter().position(|threshold| value < *threshold) {
+            Some(level) => 3 - level as u8,
+            None => 0,
+        }
+    }
+}
+
+impl Default for Sensor
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::cartridge::errors::CartridgeError;
+use crate::cartridge::hash::RomHashes;
+
+// Single dump listed in the database. No-Intro does not store the
+// region and the revision separately, they are taken from the name,
+// e.g. "Tetris (World) (Rev 1)".
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatEntry
+{
+    pub name: String,
+    pub region: Option<String>,
+    pub revision: Option<String>,
+    pub size: Option<usize>,
+    pub crc32: Option<u32>,
+    pub sha1: Option<[u8; 20]>,
+}
+
+impl DatEntry
+{
+    fn new(name: &str, attrs: &str) -> Result<Self, CartridgeError>
+    {
+        let mut tags = name
+            .split('(')
+            .skip(1)
+            .filter_map(|part| part.split_once(')').map(|(tag, _)| tag.trim()));
+
+        let region = tags.next().map(str::to_string);
+        let revision = tags
+            .find_map(|tag| tag.strip_prefix("Rev "))
+            .map(str::to_string);
+
+        let size = match attribute(attrs, "size") {
+            Some(size) => Some(
+                size.parse()
+                    .map_err(|_| CartridgeError::Database("invalid rom size"))?,
+            ),
+            None => None,
+        };
+        let crc32 = match attribute(attrs, "crc") {
+            Some(crc) => Some(
+                u32::from_str_radix(&crc, 16)
+                    .map_err(|_| CartridgeError::Database("invalid crc32"))?,
+            ),
+            None => None,
+        };
+        let sha1 = match attribute(attrs, "sha1") {
+            Some(sha1) => Some(parse_sha1(&sha1).ok_or(CartridgeError::Database("invalid sha1"))?),
+            None => None,
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            region,
+            revision,
+            size,
+            crc32,
+            sha1,
+        })
+    }
+}
+
+// Database of the known dumps in the Logiqx XML format, used by
+// No-Intro and most of the rom managers. Only the games and their roms
+// are read, everything else is skipped.
+#[derive(Clone, Debug, Default)]
+pub struct RomDatabase
+{
+    entries: Vec<DatEntry>,
+    by_sha1: HashMap<[u8; 20], usize>,
+    by_crc32: HashMap<u32, Vec<usize>>,
+}
+
+impl RomDatabase
+{
+    pub fn from_file<P>(path: P) -> Result<Self, CartridgeError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_xml(&fs::read_to_string(path)?)
+    }
+
+    pub fn from_xml(xml: &str) -> Result<Self, CartridgeError>
+    {
+        let mut database = Self::default();
+        let mut game = None;
+        let mut rest = xml;
+
+        while let Some(start) = rest.find('<') {
+            rest = &rest[start..];
+
+            // Comments are skipped as a whole, they can contain `>`.
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                let end = comment
+                    .find("-->")
+                    .ok_or(CartridgeError::Database("unterminated comment"))?;
+                rest = &comment[end + 3..];
+                continue;
+            }
+            let end = tag_end(rest).ok_or(CartridgeError::Database("unterminated tag"))?;
+            let tag = rest[1..end].trim_end_matches('/');
+            rest = &rest[end + 1..];
+
+            let (element, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+            match element {
+                "game" | "machine" => {
+                    let name = attribute(attrs, "name")
+                        .ok_or(CartridgeError::Database("game without a name"))?;
+                    game = Some(name);
+                }
+                "/game" | "/machine" => game = None,
+                "rom" => {
+                    let name = game
+                        .as_deref()
+                        .ok_or(CartridgeError::Database("rom outside of a game"))?;
+                    database.push(DatEntry::new(name, attrs)?);
+                }
+                _ => {}
+            }
+        }
+        match database.entries.is_empty() {
+            true => Err(CartridgeError::Database("no roms found")),
+            false => Ok(database),
+        }
+    }
+
+    #[inline]
+    pub fn entries(&self) -> &[DatEntry]
+    {
+        &self.entries
+    }
+
+    // The sha1 is preferred, older databases list only the crc32, in
+    // which case the size has to match as well.
+    pub fn identify(&self, hashes: &RomHashes) -> Option<&DatEntry>
+    {
+        if let Some(idx) = self.by_sha1.get(&hashes.sha1) {
+            return Some(&self.entries[*idx]);
+        }
+        self.by_crc32
+            .get(&hashes.crc32)?
+            .iter()
+            .map(|idx| &self.entries[*idx])
+            .find(|entry| entry.sha1.is_none() && entry.size.is_none_or(|size| size == hashes.size))
+    }
+
+    fn push(&mut self, entry: DatEntry)
+    {
+        let idx = self.entries.len();
+
+        if let Some(sha1) = entry.sha1 {
+            self.by_sha1.entry(sha1).or_insert(idx);
+        }
+        if let Some(crc32) = entry.crc32 {
+            self.by_crc32.entry(crc32).or_default().push(idx);
+        }
+        self.entries.push(entry);
+    }
+}
+
+// Finds the `>` closing the tag, the attribute values can contain it
+// as well.
+fn tag_end(tag: &str) -> Option<usize>
+{
+    let mut quote = None;
+
+    for (idx, ch) in tag.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), ch) if ch == open => quote = None,
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+    }
+    None
+}
+
+// Returns the unescaped value of the attribute, both quote styles are
+// allowed by XML.
+fn attribute(attrs: &str, key: &str) -> Option<String>
+{
+    let mut rest = attrs;
+
+    loop {
+        let (name, value) = rest.split_once('=')?;
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let (value, next) = value[1..].split_once(quote)?;
+
+        if name.trim() == key {
+            return Some(unescape(value));
+        }
+        rest = next;
+    }
+}
+
+// Decodes the references in a single pass, so the `&` produced by one
+// of them never starts another one. Anything which is not a valid
+// reference is kept as it is.
+fn unescape(value: &str) -> String
+{
+    let mut unescaped = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let reference = rest
+            .find(';')
+            .and_then(|end| Some((decode_reference(&rest[1..end])?, end)));
+
+        match reference {
+            Some((ch, end)) => {
+                unescaped.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+// Either one of the predefined entities or a character reference,
+// decimal (`&#233;`) or hexadecimal (`&#xE9;`).
+fn decode_reference(name: &str) -> Option<char>
+{
+    let code = match name {
+        "lt" => return Some('<'),
+        "gt" => return Some('>'),
+        "quot" => return Some('"'),
+        "apos" => return Some('\''),
+        "amp" => return Some('&'),
+        _ => match name.strip_prefix("#x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => name.strip_prefix('#')?.parse().ok()?,
+        },
+    };
+    char::from_u32(code)
+}
+
+fn parse_sha1(hex: &str) -> Option<[u8; 20]>
+{
+    if hex.len() != 40 || !hex.is_ascii() {
+        return None;
+    }
+    let mut sha1 = [0; 20];
+
+    for (byte, idx) in sha1.iter_mut().zip((0..hex.len()).step_by(2)) {
+        *byte = u8::from_str_radix(&hex[idx..idx + 2], 16).ok()?;
+    }
+    Some(sha1)
+}
@@ -0,0 +1,135 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use num_traits::FromPrimitive;
+
+use crate::cartridge::header::{CartridgeType, CgbFlag, Header, Mapper};
+use crate::cartridge::mbc::banks::ROM_BANK_SIZE;
+use crate::cartridge::validation::NINTENDO_LOGO;
+use crate::consts::rom;
+
+// Heuristics for the cartridges whose header does not tell the truth
+// about the mapper, mostly unlicensed ones.
+
+const MMM01_MENU_SIZE: usize = 0x8000;
+
+// Sachen mappers scramble the whole 0x0100 - 0x01FF area.
+const SACHEN_SCRAMBLED: Range<usize> = 0x0100..0x0200;
+
+const WISDOM_TREE_SIGNATURE: &[u8] = b"WISDOM";
+// Wisdom Tree games declare either no mapper or this invalid type.
+const WISDOM_TREE_TYPE: u8 = 0xC0;
+
+// Mbc3 can address 128 rom banks and 4 ram banks, anything larger
+// needs the `MBC30` found in the Japanese Pokemon Crystal.
+const MBC3_MAX_ROM_BANKS: usize = 128;
+const MBC3_MAX_RAM_BANKS: usize = 4;
+
+// Returns the header area as seen by the boot rom, which is not always
+// the beginning of the image.
+pub fn header_view(rom: &[u8]) -> Cow<'_, [u8]>
+{
+    if rom.len() < rom::HEADER_SIZE {
+        return Cow::Borrowed(rom);
+    }
+    if let Some(menu) = mmm01_menu(rom) {
+        return Cow::Borrowed(&rom[menu..]);
+    }
+    if is_sachen(rom) {
+        let mut view = rom.to_vec();
+
+        for addr in SACHEN_SCRAMBLED {
+            view[addr] = rom[sachen_unscramble(addr | 0x80)];
+        }
+        return Cow::Owned(view);
+    }
+    if rom[rom::CARTRIDGE_TYPE] == WISDOM_TREE_TYPE && is_wisdom_tree(rom) {
+        let mut view = rom.to_vec();
+        view[rom::CARTRIDGE_TYPE] = CartridgeType::Mbc0 as u8;
+
+        return Cow::Owned(view);
+    }
+    Cow::Borrowed(rom)
+}
+
+pub fn detect_mapper(header: &Header, rom: &[u8]) -> Mapper
+{
+    if is_sachen(rom) {
+        // The unlocked header of the games which run in the color mode.
+        let cgb = CgbFlag::from_u8(rom[sachen_unscramble(rom::CGB_FLAG)]);
+
+        return match cgb.is_present() {
+            true => Mapper::SachenMmc2,
+            false => Mapper::SachenMmc1,
+        };
+    }
+    match header.cartridge_type.mapper() {
+        Mapper::None if is_wisdom_tree(rom) => Mapper::WisdomTree,
+        Mapper::Mbc3
+            if header.rom_banks > MBC3_MAX_ROM_BANKS || header.ram_banks > MBC3_MAX_RAM_BANKS =>
+        {
+            Mapper::Mbc30
+        }
+        mapper => mapper,
+    }
+}
+
+// MMM01 multicarts boot into the menu stored in the last 32 KiB of
+// the rom, so the header of the cartridge is there as well. The header
+// at the beginning belongs to the first game.
+pub fn mmm01_menu(rom: &[u8]) -> Option<usize>
+{
+    let menu = rom
+        .len()
+        .checked_sub(MMM01_MENU_SIZE)
+        .filter(|menu| *menu > 0)?;
+    let header = &rom[menu..];
+
+    let is_mmm01 = CartridgeType::from_u8(header[rom::CARTRIDGE_TYPE])
+        .is_some_and(|kind| kind.mapper() == Mapper::Mmm01);
+
+    // About one rom in a hundred has one of the MMM01 types at this
+    // offset by chance, only a real menu also has a header which would
+    // pass the checks of the boot rom.
+    let is_bootable = Header::has_valid_logo(header)
+        && Header::compute_header_checksum(header) == header[rom::CHECKSUM];
+
+    (is_mmm01 && is_bootable).then_some(menu)
+}
+
+// Sachen mappers swap the address lines 0, 1, 4 and 6 when reading from
+// 0x0100 - 0x01FF, and until the boot rom finishes, they also set the
+// line 7. The boot rom sees the Nintendo logo, while the game shows its
+// own one stored at the original location.
+pub fn sachen_unscramble(addr: usize) -> usize
+{
+    (addr & !0x53)
+        | (addr & 0x40) >> 6
+        | (addr & 0x10) >> 3
+        | (addr & 0x02) << 3
+        | (addr & 0x01) << 6
+}
+
+fn is_sachen(rom: &[u8]) -> bool
+{
+    if rom.len() < SACHEN_SCRAMBLED.end {
+        return false;
+    }
+    let locked_logo =
+        (rom::LOGO_START..rom::LOGO_END).map(|addr| rom[sachen_unscramble(addr | 0x80)]);
+
+    !Header::has_valid_logo(rom) && locked_logo.eq(NINTENDO_LOGO)
+}
+
+fn is_wisdom_tree(rom: &[u8]) -> bool
+{
+    let title = &rom[rom::TITLE_START..rom::TITLE_END];
+
+    rom.len() > 2 * ROM_BANK_SIZE
+        && title
+            .windows(WISDOM_TREE_SIGNATURE.len())
+            .any(|window| window == WISDOM_TREE_SIGNATURE)
+}
@@ -11,4 +11,99 @@ pub enum CartridgeError
 
     #[error("Problem with rom header: {0}")]
     Header(&'static str),
+
+    #[error("Cartridge rejected by validation: {0}")]
+    Rejected(CartridgeWarning),
+
+    #[error("Rom image is too large, {0} bytes exceed the 8 MiB limit.")]
+    RomTooLarge(usize),
+
+    #[error("Problem with archive: {0}.")]
+    Archive(&'static str),
+
+    #[error("Archive does not contain the entry: {0}.")]
+    ArchiveEntryNotFound(String),
+
+    #[error("Problem with camera image: {0}.")]
+    CameraImage(&'static str),
+
+    #[error("Problem with rom database: {0}.")]
+    Database(&'static str),
+
+    #[error("Problem with patch: {0}.")]
+    Patch(&'static str),
+
+    #[error(
+        "Patch was made for a different rom, expected crc32 0x{expected:08X}, computed \
+         0x{computed:08X}."
+    )]
+    PatchSourceMismatch
+    {
+        expected: u32, computed: u32
+    },
+
+    #[error(
+        "Patched rom is corrupted, expected crc32 0x{expected:08X}, computed 0x{computed:08X}."
+    )]
+    PatchTargetMismatch
+    {
+        expected: u32, computed: u32
+    },
+
+    #[error("Patch file is corrupted, expected crc32 0x{expected:08X}, computed 0x{computed:08X}.")]
+    PatchCorrupted
+    {
+        expected: u32, computed: u32
+    },
+}
+
+// Problems which do not prevent the cartridge from being emulated,
+// but suggest that the rom is either broken or was modified.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum CartridgeWarning
+{
+    #[error("Nintendo logo in the header is corrupted.")]
+    InvalidLogo,
+
+    #[error("Header checksum mismatch, expected 0x{expected:02X}, computed 0x{computed:02X}.")]
+    HeaderChecksum
+    {
+        expected: u8, computed: u8
+    },
+
+    #[error("Global checksum mismatch, expected 0x{expected:04X}, computed 0x{computed:04X}.")]
+    GlobalChecksum
+    {
+        expected: u16, computed: u16
+    },
+
+    #[error("Unknown rom size code 0x{0:02X}, the size of the image is used instead.")]
+    UnknownRomSize(u8),
+
+    #[error("Rom image is truncated, expected {expected} bytes, found {actual}.")]
+    RomTruncated
+    {
+        expected: usize, actual: usize
+    },
+
+    #[error("Rom image is overdumped, expected {expected} bytes, found {actual}.")]
+    RomOverdumped
+    {
+        expected: usize, actual: usize
+    },
+
+    #[error("Rom image is larger than declared, expected {expected} bytes, found {actual}.")]
+    RomLargerThanDeclared
+    {
+        expected: usize, actual: usize
+    },
+}
+
+impl CartridgeWarning
+{
+    // Returns true if the real hardware would refuse to boot the game.
+    pub fn is_fatal(&self) -> bool
+    {
+        matches!(self, Self::InvalidLogo | Self::HeaderChecksum { .. })
+    }
 }
@@ -0,0 +1,139 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+// The standard CRC-32 (IEEE 802.3), the same one as used by zip, the
+// patch formats and the rom databases.
+const CRC32_POLYNOMIAL: u32 = 0xEDB88320;
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256]
+{
+    let mut table = [0; 256];
+    let mut idx = 0;
+
+    while idx < 256 {
+        let mut crc = idx as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ CRC32_POLYNOMIAL,
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+        table[idx] = crc;
+        idx += 1;
+    }
+    table
+}
+
+pub fn crc32(data: &[u8]) -> u32
+{
+    let crc = data.iter().fold(0xFFFFFFFF, |crc: u32, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    });
+    !crc
+}
+
+// The SHA-1 is used only to identify the roms, so it does not matter
+// that it is no longer considered secure.
+const SHA1_INIT: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+const SHA1_BLOCK_SIZE: usize = 64;
+
+pub fn sha1(data: &[u8]) -> [u8; 20]
+{
+    let mut state = SHA1_INIT;
+
+    // The message is padded with a single set bit, zeros and its
+    // length in bits, so it fills a whole number of blocks.
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    let blocks = data.chunks_exact(SHA1_BLOCK_SIZE);
+    let rest = blocks.remainder();
+
+    let mut tail = rest.to_vec();
+    tail.push(0x80);
+
+    while tail.len() % SHA1_BLOCK_SIZE != SHA1_BLOCK_SIZE - 8 {
+        tail.push(0x00);
+    }
+    tail.extend_from_slice(&bit_len.to_be_bytes());
+
+    for block in blocks.chain(tail.chunks_exact(SHA1_BLOCK_SIZE)) {
+        sha1_block(&mut state, block);
+    }
+
+    let mut digest = [0; 20];
+
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+fn sha1_block(state: &mut [u32; 5], block: &[u8])
+{
+    let mut words = [0u32; 80];
+
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    for idx in 16..80 {
+        words[idx] =
+            (words[idx - 3] ^ words[idx - 8] ^ words[idx - 14] ^ words[idx - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+
+    for (idx, word) in words.iter().enumerate() {
+        let (f, k) = match idx {
+            0..20 => ((b & c) | (!b & d), 0x5A827999),
+            20..40 => (b ^ c ^ d, 0x6ED9EBA1),
+            40..60 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+            _ => (b ^ c ^ d, 0xCA62C1D6),
+        };
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(*word);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    for (value, add) in state.iter_mut().zip([a, b, c, d, e]) {
+        *value = value.wrapping_add(add);
+    }
+}
+
+// Hashes of the rom image as it was loaded, before it was padded or
+// trimmed, so they can be compared with the rom databases.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RomHashes
+{
+    pub crc32: u32,
+    pub sha1: [u8; 20],
+    pub size: usize,
+}
+
+impl RomHashes
+{
+    pub fn new(rom: &[u8]) -> Self
+    {
+        Self {
+            crc32: crc32(rom),
+            sha1: sha1(rom),
+            size: rom.len(),
+        }
+    }
+
+    pub fn sha1_hex(&self) -> String
+    {
+        self.sha1.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
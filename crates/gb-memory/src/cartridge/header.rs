@@ -1,39 +1,378 @@
 // Copyright: (c) 2025, Wiktor Nowak
 // GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
 
+use std::fmt;
+
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
 
+use crate::cartridge::detection;
 use crate::cartridge::errors::CartridgeError;
+use crate::cartridge::licensee::Licensee;
+use crate::cartridge::mbc::banks::ROM_BANK_SIZE;
+use crate::cartridge::validation::{Checksum, NINTENDO_LOGO, ValidationReport};
 use crate::consts::rom;
 
-#[repr(u8)]
-#[derive(Clone, Copy, Debug, FromPrimitive)]
+// On older cartridges the byte of the cgb flag is the last character
+// of the title, so everything without bit 7 set is a `DMG` game. The
+// hardware ignores bit 6, but values with bit 2 or 3 set switch the
+// `CGB` into the non-color `PGB` mode.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CgbFlag
 {
-    SupportsEnhancements = 0x80,
-    CgbOnly              = 0xC0,
+    DmgOnly,
+    SupportsEnhancements,
+    CgbOnly,
+    Pgb,
+}
+
+impl CgbFlag
+{
+    pub fn from_u8(val: u8) -> Self
+    {
+        match val {
+            0x00..=0x7F => Self::DmgOnly,
+            0xC0 => Self::CgbOnly,
+            _ if val & 0x0C != 0 => Self::Pgb,
+            _ => Self::SupportsEnhancements,
+        }
+    }
+
+    #[inline]
+    pub fn is_present(&self) -> bool
+    {
+        *self != Self::DmgOnly
+    }
+}
+
+impl fmt::Display for CgbFlag
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let desc = match self {
+            Self::DmgOnly => "DMG only",
+            Self::SupportsEnhancements => "supports CGB enhancements",
+            Self::CgbOnly => "CGB only",
+            Self::Pgb => "PGB mode",
+        };
+        f.write_str(desc)
+    }
+}
+
+// The `SGB` functions are enabled only if the byte is exactly 0x03,
+// every other value disables them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SgbFlag
+{
+    Unsupported,
+    Supported,
+}
+
+impl SgbFlag
+{
+    pub fn from_u8(val: u8) -> Self
+    {
+        match val {
+            0x03 => Self::Supported,
+            _ => Self::Unsupported,
+        }
+    }
+}
+
+impl fmt::Display for SgbFlag
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let desc = match self {
+            Self::Unsupported => "unsupported",
+            Self::Supported => "supported",
+        };
+        f.write_str(desc)
+    }
+}
+
+// Only 0x00 (Japan) and 0x01 (overseas) are documented. The value
+// is not checked by the hardware, so everything else is treated as
+// an overseas release.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Destination
+{
+    Japan,
+    Overseas,
+}
+
+impl Destination
+{
+    pub fn from_u8(val: u8) -> Self
+    {
+        match val {
+            0x00 => Self::Japan,
+            _ => Self::Overseas,
+        }
+    }
+}
+
+impl fmt::Display for Destination
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let desc = match self {
+            Self::Japan => "Japan",
+            Self::Overseas => "overseas",
+        };
+        f.write_str(desc)
+    }
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, FromPrimitive)]
+#[derive(Clone, Copy, Debug, FromPrimitive, PartialEq)]
 pub enum CartridgeType
 {
-    Mbc0                  = 0x00,
-    Mbc1                  = 0x01,
-    Mbc1WithRam           = 0x02,
-    Mbc1WithRamAndBattery = 0x03,
+    Mbc0                              = 0x00,
+    Mbc1                              = 0x01,
+    Mbc1WithRam                       = 0x02,
+    Mbc1WithRamAndBattery             = 0x03,
+    Mbc2                              = 0x05,
+    Mbc2WithBattery                   = 0x06,
+    Mbc0WithRam                       = 0x08,
+    Mbc0WithRamAndBattery             = 0x09,
+    Mmm01                             = 0x0B,
+    Mmm01WithRam                      = 0x0C,
+    Mmm01WithRamAndBattery            = 0x0D,
+    Mbc3WithTimerAndBattery           = 0x0F,
+    Mbc3WithTimerRamAndBattery        = 0x10,
+    Mbc3                              = 0x11,
+    Mbc3WithRam                       = 0x12,
+    Mbc3WithRamAndBattery             = 0x13,
+    Mbc5                              = 0x19,
+    Mbc5WithRam                       = 0x1A,
+    Mbc5WithRamAndBattery             = 0x1B,
+    Mbc5WithRumble                    = 0x1C,
+    Mbc5WithRumbleAndRam              = 0x1D,
+    Mbc5WithRumbleRamAndBattery       = 0x1E,
+    Mbc6                              = 0x20,
+    Mbc7WithSensorRumbleRamAndBattery = 0x22,
+    PocketCamera                      = 0xFC,
+    Tama5                             = 0xFD,
+    HuC3                              = 0xFE,
+    HuC1WithRamAndBattery             = 0xFF,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mapper
+{
+    None,
+    Mbc1,
+    Mbc2,
+    Mmm01,
+    Mbc3,
+    Mbc5,
+    Mbc6,
+    Mbc7,
+    PocketCamera,
+    Tama5,
+    HuC3,
+    HuC1,
+    // Mappers which can not be declared in the header and are only
+    // recognized by heuristics.
+    Mbc30,
+    WisdomTree,
+    SachenMmc1,
+    SachenMmc2,
+}
+
+impl CartridgeType
+{
+    pub fn mapper(&self) -> Mapper
+    {
+        match self {
+            Self::Mbc0 | Self::Mbc0WithRam | Self::Mbc0WithRamAndBattery => Mapper::None,
+            Self::Mbc1 | Self::Mbc1WithRam | Self::Mbc1WithRamAndBattery => Mapper::Mbc1,
+            Self::Mbc2 | Self::Mbc2WithBattery => Mapper::Mbc2,
+            Self::Mmm01 | Self::Mmm01WithRam | Self::Mmm01WithRamAndBattery => Mapper::Mmm01,
+            Self::Mbc3WithTimerAndBattery
+            | Self::Mbc3WithTimerRamAndBattery
+            | Self::Mbc3
+            | Self::Mbc3WithRam
+            | Self::Mbc3WithRamAndBattery => Mapper::Mbc3,
+            Self::Mbc5
+            | Self::Mbc5WithRam
+            | Self::Mbc5WithRamAndBattery
+            | Self::Mbc5WithRumble
+            | Self::Mbc5WithRumbleAndRam
+            | Self::Mbc5WithRumbleRamAndBattery => Mapper::Mbc5,
+            Self::Mbc6 => Mapper::Mbc6,
+            Self::Mbc7WithSensorRumbleRamAndBattery => Mapper::Mbc7,
+            Self::PocketCamera => Mapper::PocketCamera,
+            Self::Tama5 => Mapper::Tama5,
+            Self::HuC3 => Mapper::HuC3,
+            Self::HuC1WithRamAndBattery => Mapper::HuC1,
+        }
+    }
+
+    // `MBC2` and the mappers without the `RAM` in the name still come
+    // with memory built into the mapper chip itself.
+    pub fn has_ram(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Mbc1WithRam
+                | Self::Mbc1WithRamAndBattery
+                | Self::Mbc2
+                | Self::Mbc2WithBattery
+                | Self::Mbc0WithRam
+                | Self::Mbc0WithRamAndBattery
+                | Self::Mmm01WithRam
+                | Self::Mmm01WithRamAndBattery
+                | Self::Mbc3WithTimerRamAndBattery
+                | Self::Mbc3WithRam
+                | Self::Mbc3WithRamAndBattery
+                | Self::Mbc5WithRam
+                | Self::Mbc5WithRamAndBattery
+                | Self::Mbc5WithRumbleAndRam
+                | Self::Mbc5WithRumbleRamAndBattery
+                | Self::Mbc6
+                | Self::Mbc7WithSensorRumbleRamAndBattery
+                | Self::PocketCamera
+                | Self::Tama5
+                | Self::HuC3
+                | Self::HuC1WithRamAndBattery
+        )
+    }
+
+    pub fn has_battery(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Mbc1WithRamAndBattery
+                | Self::Mbc2WithBattery
+                | Self::Mbc0WithRamAndBattery
+                | Self::Mmm01WithRamAndBattery
+                | Self::Mbc3WithTimerAndBattery
+                | Self::Mbc3WithTimerRamAndBattery
+                | Self::Mbc3WithRamAndBattery
+                | Self::Mbc5WithRamAndBattery
+                | Self::Mbc5WithRumbleRamAndBattery
+                | Self::Mbc6
+                | Self::Mbc7WithSensorRumbleRamAndBattery
+                | Self::PocketCamera
+                | Self::Tama5
+                | Self::HuC3
+                | Self::HuC1WithRamAndBattery
+        )
+    }
+
+    pub fn has_rtc(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Mbc3WithTimerAndBattery
+                | Self::Mbc3WithTimerRamAndBattery
+                | Self::Tama5
+                | Self::HuC3
+        )
+    }
+
+    pub fn has_rumble(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Mbc5WithRumble
+                | Self::Mbc5WithRumbleAndRam
+                | Self::Mbc5WithRumbleRamAndBattery
+                | Self::Mbc7WithSensorRumbleRamAndBattery
+        )
+    }
+
+    // The accelerometer of `MBC7` and the image sensor of the camera.
+    pub fn has_sensor(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Mbc7WithSensorRumbleRamAndBattery | Self::PocketCamera
+        )
+    }
+}
+
+impl fmt::Display for CartridgeType
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let name = match self {
+            Self::Mbc0 => "ROM ONLY",
+            Self::Mbc1 => "MBC1",
+            Self::Mbc1WithRam => "MBC1+RAM",
+            Self::Mbc1WithRamAndBattery => "MBC1+RAM+BATTERY",
+            Self::Mbc2 => "MBC2",
+            Self::Mbc2WithBattery => "MBC2+BATTERY",
+            Self::Mbc0WithRam => "ROM+RAM",
+            Self::Mbc0WithRamAndBattery => "ROM+RAM+BATTERY",
+            Self::Mmm01 => "MMM01",
+            Self::Mmm01WithRam => "MMM01+RAM",
+            Self::Mmm01WithRamAndBattery => "MMM01+RAM+BATTERY",
+            Self::Mbc3WithTimerAndBattery => "MBC3+TIMER+BATTERY",
+            Self::Mbc3WithTimerRamAndBattery => "MBC3+TIMER+RAM+BATTERY",
+            Self::Mbc3 => "MBC3",
+            Self::Mbc3WithRam => "MBC3+RAM",
+            Self::Mbc3WithRamAndBattery => "MBC3+RAM+BATTERY",
+            Self::Mbc5 => "MBC5",
+            Self::Mbc5WithRam => "MBC5+RAM",
+            Self::Mbc5WithRamAndBattery => "MBC5+RAM+BATTERY",
+            Self::Mbc5WithRumble => "MBC5+RUMBLE",
+            Self::Mbc5WithRumbleAndRam => "MBC5+RUMBLE+RAM",
+            Self::Mbc5WithRumbleRamAndBattery => "MBC5+RUMBLE+RAM+BATTERY",
+            Self::Mbc6 => "MBC6",
+            Self::Mbc7WithSensorRumbleRamAndBattery => "MBC7+SENSOR+RUMBLE+RAM+BATTERY",
+            Self::PocketCamera => "POCKET CAMERA",
+            Self::Tama5 => "BANDAI TAMA5",
+            Self::HuC3 => "HuC3",
+            Self::HuC1WithRamAndBattery => "HuC1+RAM+BATTERY",
+        };
+        write!(f, "{name} (0x{:02X})", *self as u8)
+    }
+}
+
+impl fmt::Display for Mapper
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let name = match self {
+            Self::None => "no mapper",
+            Self::Mbc1 => "MBC1",
+            Self::Mbc2 => "MBC2",
+            Self::Mmm01 => "MMM01",
+            Self::Mbc3 => "MBC3",
+            Self::Mbc5 => "MBC5",
+            Self::Mbc6 => "MBC6",
+            Self::Mbc7 => "MBC7",
+            Self::PocketCamera => "Pocket Camera",
+            Self::Tama5 => "TAMA5",
+            Self::HuC3 => "HuC3",
+            Self::HuC1 => "HuC1",
+            Self::Mbc30 => "MBC30",
+            Self::WisdomTree => "Wisdom Tree",
+            Self::SachenMmc1 => "Sachen MMC1",
+            Self::SachenMmc2 => "Sachen MMC2",
+        };
+        f.write_str(name)
+    }
 }
 
 #[derive(Debug)]
 pub struct Header
 {
     pub title: String,
+    pub manufacturer_code: Option<String>,
     pub cgb: CgbFlag,
+    pub licensee: Licensee,
+    pub sgb: SgbFlag,
     pub cartridge_type: CartridgeType,
     pub rom_banks: usize,
     pub ram_banks: usize,
+    pub destination: Destination,
+    pub rom_version: u8,
     pub header_checksum: u8,
+    pub global_checksum: u16,
 }
 
 impl Header
@@ -45,10 +384,15 @@ impl Header
                 "Rom is too short to contain a header.",
             ));
         }
-        let title = read_game_title(rom);
+        let cgb = CgbFlag::from_u8(rom[rom::CGB_FLAG]);
+        let manufacturer_code = read_manufacturer_code(rom, cgb);
+        let title = read_game_title(rom, cgb, manufacturer_code.is_some());
 
-        let cgb = CgbFlag::from_u8(rom[rom::CGB_FLAG])
-            .ok_or(CartridgeError::Header("Invalid value of cgb flag."))?;
+        let licensee = Licensee::new(
+            rom[rom::OLD_LICENSEE_CODE],
+            [rom[rom::NEW_LICENSEE_CODE], rom[rom::NEW_LICENSEE_CODE + 1]],
+        );
+        let sgb = SgbFlag::from_u8(rom[rom::SGB_FLAG]);
 
         let cartridge_type = CartridgeType::from_u8(rom[rom::CARTRIDGE_TYPE])
             .ok_or(CartridgeError::Header("Invalid value for catridge type."))?;
@@ -56,37 +400,178 @@ impl Header
         let rom_banks = read_rom_size(rom);
         let ram_banks = read_ram_size(rom);
 
+        let destination = Destination::from_u8(rom[rom::DESTINATION_CODE]);
+
+        // The global checksum is the only big-endian value in the header.
+        let global_checksum =
+            u16::from_be_bytes([rom[rom::GLOBAL_CHECKSUM], rom[rom::GLOBAL_CHECKSUM + 1]]);
+
         Ok(Self {
             title,
+            manufacturer_code,
             cgb,
+            licensee,
+            sgb,
             cartridge_type,
             rom_banks,
             ram_banks,
+            destination,
+            rom_version: rom[rom::ROM_VERSION],
             header_checksum: rom[rom::CHECKSUM],
+            global_checksum,
         })
     }
+
+    // Returns the number of rom banks declared by the rom size code,
+    // or `None` if the code is not one of the documented values.
+    pub fn rom_banks_from_code(code: u8) -> Option<usize>
+    {
+        match code {
+            0x00..=0x08 => Some(2 << code),
+            _ => None,
+        }
+    }
+
+    // Number of banks needed to hold the image, rounded up to the next
+    // power of two like on the real cartridges.
+    pub fn rom_banks_from_len(len: usize) -> usize
+    {
+        len.div_ceil(ROM_BANK_SIZE).next_power_of_two().max(2)
+    }
+
+    // Offset of the header seen by the boot rom, on MMM01 multicarts it
+    // is a part of the menu at the end of the image.
+    pub fn offset(rom: &[u8]) -> usize
+    {
+        detection::mmm01_menu(rom).unwrap_or(0)
+    }
+
+    // All of the functions below expect the rom to be at least long
+    // enough to contain the whole header.
+
+    pub fn compute_header_checksum(rom: &[u8]) -> u8
+    {
+        rom[rom::TITLE_START..rom::CHECKSUM]
+            .iter()
+            .fold(0u8, |acc, byte| acc.wrapping_sub(*byte).wrapping_sub(1))
+    }
+
+    pub fn compute_global_checksum(rom: &[u8]) -> u16
+    {
+        let global_checksum = rom::GLOBAL_CHECKSUM..rom::GLOBAL_CHECKSUM + 2;
+
+        rom.iter()
+            .enumerate()
+            .filter(|(idx, _)| !global_checksum.contains(idx))
+            .fold(0u16, |acc, (_, byte)| acc.wrapping_add(*byte as u16))
+    }
+
+    // Unlike the rest of the header, the global checksum covers the whole
+    // image, only the checksum itself is skipped wherever the header is.
+    pub fn compute_image_checksum(rom: &[u8]) -> u16
+    {
+        let offset = Self::offset(rom);
+
+        rom[..offset].iter().fold(
+            Self::compute_global_checksum(&rom[offset..]),
+            |acc, byte| acc.wrapping_add(*byte as u16),
+        )
+    }
+
+    pub fn has_valid_logo(rom: &[u8]) -> bool
+    {
+        rom[rom::LOGO_START..rom::LOGO_END] == NINTENDO_LOGO
+    }
+
+    pub fn validate(&self, rom: &[u8]) -> ValidationReport
+    {
+        ValidationReport {
+            logo_valid: Self::has_valid_logo(rom),
+            header_checksum: Checksum {
+                expected: self.header_checksum,
+                computed: Self::compute_header_checksum(rom),
+            },
+            global_checksum: Checksum {
+                expected: self.global_checksum,
+                computed: Self::compute_global_checksum(rom),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Header
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let manufacturer_code = self.manufacturer_code.as_deref().unwrap_or("-");
+
+        writeln!(f, "Title:             {}", self.title)?;
+        writeln!(f, "Manufacturer code: {manufacturer_code}")?;
+        writeln!(f, "Licensee:          {}", self.licensee)?;
+        writeln!(f, "CGB flag:          {}", self.cgb)?;
+        writeln!(f, "SGB flag:          {}", self.sgb)?;
+        writeln!(f, "Cartridge type:    {}", self.cartridge_type)?;
+        writeln!(
+            f,
+            "ROM size:          {} banks ({} KiB)",
+            self.rom_banks,
+            self.rom_banks * 16
+        )?;
+        writeln!(
+            f,
+            "RAM size:          {} banks ({} KiB)",
+            self.ram_banks,
+            self.ram_banks * 8
+        )?;
+        writeln!(f, "Destination:       {}", self.destination)?;
+        writeln!(f, "ROM version:       0x{:02X}", self.rom_version)?;
+        writeln!(f, "Header checksum:   0x{:02X}", self.header_checksum)?;
+        write!(f, "Global checksum:   0x{:04X}", self.global_checksum)
+    }
 }
 
-fn read_game_title(rom: &[u8]) -> String
+fn read_game_title(rom: &[u8], cgb: CgbFlag, has_manufacturer_code: bool) -> String
 {
-    let title_bytes = &rom[rom::TITLE_START..rom::TITLE_END];
-    String::from_utf8_lossy(title_bytes).to_string()
+    // The title takes all 16 bytes on the old cartridges and shrinks
+    // to 15 bytes when the cgb flag is present, or to 11 bytes if the
+    // manufacturer code is present as well.
+    let title_end = match cgb.is_present() {
+        true if has_manufacturer_code => rom::MANUFACTURER_CODE_START,
+        true => rom::CGB_FLAG,
+        false => rom::TITLE_END,
+    };
+    let title_bytes = &rom[rom::TITLE_START..title_end];
+
+    String::from_utf8_lossy(title_bytes)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn read_manufacturer_code(rom: &[u8], cgb: CgbFlag) -> Option<String>
+{
+    // There is no flag telling if the code is present, so we have to
+    // guess based on its format. The code exists only on cartridges
+    // with the cgb flag, is made of 4 upper case characters and, for
+    // all known releases, starts with one of the letters describing
+    // the type of the game.
+    let code = &rom[rom::MANUFACTURER_CODE_START..rom::MANUFACTURER_CODE_END];
+
+    let valid_chars = code
+        .iter()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+
+    let is_present =
+        cgb.is_present() && valid_chars && matches!(code[0], b'A' | b'B' | b'H' | b'K' | b'V');
+
+    is_present.then(|| String::from_utf8_lossy(code).to_string())
 }
 
 fn read_rom_size(rom: &[u8]) -> usize
 {
-    match rom[rom::ROM_SIZE] {
-        0x00 => 2,
-        0x01 => 4,
-        0x02 => 8,
-        0x03 => 16,
-        0x04 => 32,
-        0x05 => 64,
-        0x06 => 128,
-        0x07 => 256,
-        0x08 => 512,
-        _ => 2,
-    }
+    // If the header does not know the size, the image is the only
+    // source of truth we have left.
+    Header::rom_banks_from_code(rom[rom::ROM_SIZE])
+        .unwrap_or_else(|| Header::rom_banks_from_len(rom.len()))
 }
 
 fn read_ram_size(rom: &[u8]) -> usize
@@ -96,7 +581,7 @@ fn read_ram_size(rom: &[u8]) -> usize
         0x02 => 1,
         0x03 => 4,
         0x04 => 16,
-        0x05 => 64,
+        0x05 => 8,
         _ => 0,
     }
 }
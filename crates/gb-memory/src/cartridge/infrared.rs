@@ -0,0 +1,70 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Connects the infrared LED and sensor of the cartridge to the outside
+// world. Without a channel the sensor never sees any light.
+pub trait InfraredChannel: Send
+{
+    // Called whenever the game switches the LED.
+    fn set_led(&mut self, on: bool);
+
+    // Returns true if the sensor currently sees the light.
+    fn is_receiving(&self) -> bool;
+}
+
+// The channel is usually shared with another instance, so it is never
+// queried just to be printed.
+impl fmt::Debug for dyn InfraredChannel
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str("dyn InfraredChannel")
+    }
+}
+
+// One end of a local link between two emulator instances, possibly
+// running on different threads. The sensor of every end sees the LED
+// of the other one.
+#[derive(Debug, Clone)]
+pub struct InfraredLink
+{
+    led: Arc<AtomicBool>,
+    remote_led: Arc<AtomicBool>,
+}
+
+impl InfraredLink
+{
+    // Returns both ends of the link, one for each cartridge.
+    pub fn pair() -> (Self, Self)
+    {
+        let first = Arc::new(AtomicBool::new(false));
+        let second = Arc::new(AtomicBool::new(false));
+
+        let near = Self {
+            led: first.clone(),
+            remote_led: second.clone(),
+        };
+        let far = Self {
+            led: second,
+            remote_led: first,
+        };
+        (near, far)
+    }
+}
+
+impl InfraredChannel for InfraredLink
+{
+    fn set_led(&mut self, on: bool)
+    {
+        self.led.store(on, Ordering::Relaxed);
+    }
+
+    fn is_receiving(&self) -> bool
+    {
+        self.remote_led.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::chunk::MemoryChunk;
+
+pub const ROM_BANK_SIZE: usize = 0x4000;
+pub const RAM_BANK_SIZE: usize = 0x2000;
+
+// The largest rom supported by any of the official mappers (`MBC5`).
+pub const MAX_ROM_BANKS: usize = 512;
+
+pub type RomBank = MemoryChunk<0x0000, ROM_BANK_SIZE>;
+pub type RamBank = MemoryChunk<0xA000, RAM_BANK_SIZE>;
+
+// The mappers have only as many address lines connected as needed
+// for the memory which is actually present on the cartridge. Bank
+// numbers larger than that simply wrap around, so selecting a bank
+// which does not exist never fails.
+
+#[derive(Debug)]
+pub struct RomBanks
+{
+    // All 16 KiB banks of the rom, the bank 0 included.
+    banks: Vec<RomBank>,
+}
+
+impl RomBanks
+{
+    pub fn new(rom: &[u8]) -> Self
+    {
+        let banks = rom.chunks(ROM_BANK_SIZE).map(RomBank::from_slice).collect();
+        Self { banks }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.banks.len()
+    }
+
+    #[inline]
+    pub fn bank(&self, bank: usize) -> &RomBank
+    {
+        &self.banks[bank % self.banks.len()]
+    }
+
+    // The address can point to either of the rom areas, only the offset
+    // within the bank is used.
+    #[inline]
+    pub fn read_byte(&self, bank: usize, addr: u16) -> u8
+    {
+        self.bank(bank).read_byte(addr & 0x3FFF)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RamBanks
+{
+    banks: Vec<RamBank>,
+}
+
+impl RamBanks
+{
+    pub fn new(count: usize) -> Self
+    {
+        let banks = (0..count).map(|_| RamBank::new()).collect();
+        Self { banks }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.banks.len()
+    }
+
+    // Reading from a cartridge without ram returns the value of
+    // the open bus.
+    #[inline]
+    pub fn read_byte(&self, bank: usize, addr: u16) -> u8
+    {
+        match self.banks.len() {
+            0 => 0xFF,
+            len => self.banks[bank % len].read_byte(addr),
+        }
+    }
+
+    #[inline]
+    pub fn write_byte(&mut self, bank: usize, addr: u16, val: u8)
+    {
+        let len = self.banks.len();
+
+        if len > 0 {
+            self.banks[bank % len].write_byte(addr, val);
+        }
+    }
+
+    pub fn dump(&self) -> Vec<u8>
+    {
+        self.banks
+            .iter()
+            .flat_map(|bank| bank.as_slice())
+            .copied()
+            .collect()
+    }
+
+    pub fn load(&mut self, data: &[u8])
+    {
+        for (bank, chunk) in self.banks.iter_mut().zip(data.chunks(RAM_BANK_SIZE)) {
+            bank.as_mut_slice()[..chunk.len()].copy_from_slice(chunk);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+// Number of 16-bit words in the `93LC56` chip.
+pub const EEPROM_WORDS: usize = 128;
+
+// Every command starts with the start bit, followed by 2 bits of the
+// opcode and 8 bits of the address (the highest one is ignored).
+const COMMAND_BITS: u8 = 11;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State
+{
+    // Waiting for the start bit or shifting in the command.
+    Command,
+    // Shifting in the 16-bit value for `WRITE` or `WRAL`.
+    Data
+    {
+        addr: Option<u8>,
+    },
+    // Shifting out the words starting from the given address.
+    Read
+    {
+        addr: u8,
+        bit: u8,
+    },
+    // The command finished, waiting for the chip select to go low.
+    Done,
+}
+
+// Serial `93LC56` eeprom in the 16-bit organisation, bit-banged by the
+// game through the chip select, clock and data lines. Inputs are
+// sampled and the output is shifted on the rising edge of the clock.
+#[derive(Debug)]
+pub struct Eeprom
+{
+    words: [u16; EEPROM_WORDS],
+    write_enabled: bool,
+    state: State,
+    shift: u16,
+    bits: u8,
+    cs: bool,
+    clk: bool,
+    di: bool,
+    // The chip reports it is ready after every erase or write.
+    out: bool,
+}
+
+impl Eeprom
+{
+    pub fn new() -> Self
+    {
+        Self {
+            words: [0xFFFF; EEPROM_WORDS],
+            write_enabled: false,
+            state: State::Command,
+            shift: 0,
+            bits: 0,
+            cs: false,
+            clk: false,
+            di: false,
+            out: true,
+        }
+    }
+
+    // Bit 7 is the chip select, bit 6 the clock, bit 1 the data input
+    // and bit 0 the data output.
+    pub fn read(&self) -> u8
+    {
+        (self.cs as u8) << 7 | (self.clk as u8) << 6 | (self.di as u8) << 1 | self.out as u8
+    }
+
+    pub fn write(&mut self, val: u8)
+    {
+        let cs = val & 0x80 != 0;
+        let clk = val & 0x40 != 0;
+        self.di = val & 0x02 != 0;
+
+        if !cs {
+            self.state = State::Command;
+            self.bits = 0;
+            self.shift = 0;
+        } else if clk && !self.clk {
+            self.clock();
+        }
+        self.cs = cs;
+        self.clk = clk;
+    }
+
+    pub fn dump(&self) -> Vec<u8>
+    {
+        self.words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    pub fn load(&mut self, data: &[u8])
+    {
+        for (word, bytes) in self.words.iter_mut().zip(data.chunks_exact(2)) {
+            *word = u16::from_le_bytes([bytes[0], bytes[1]]);
+        }
+    }
+
+    fn clock(&mut self)
+    {
+        match self.state {
+            State::Command => {
+                // Leading zeros before the start bit are ignored.
+                if self.bits == 0 && !self.di {
+                    return;
+                }
+                self.shift_in();
+
+                if self.bits == COMMAND_BITS {
+                    self.execute();
+                }
+            }
+            State::Data { addr } => {
+                self.shift_in();
+
+                if self.bits == 16 {
+                    self.program(addr, self.shift);
+                }
+            }
+            State::Read { addr, bit } => {
+                // The first bit is a dummy zero, then the words follow
+                // with the most significant bit first.
+                let (addr, bit) = match bit {
+                    16 => (addr.wrapping_add(1) % EEPROM_WORDS as u8, 0),
+                    bit => (addr, bit),
+                };
+                self.out = self.words[addr as usize] & (0x8000 >> bit) != 0;
+                self.state = State::Read { addr, bit: bit + 1 };
+            }
+            State::Done => {}
+        }
+    }
+
+    fn shift_in(&mut self)
+    {
+        self.shift = (self.shift << 1) | self.di as u16;
+        self.bits += 1;
+    }
+
+    fn execute(&mut self)
+    {
+        let opcode = (self.shift >> 8) & 0x03;
+        // Commands without an address are told apart by the highest
+        // address bits.
+        let extension = (self.shift >> 6) & 0x03;
+        let addr = (self.shift & 0x7F) as u8;
+
+        self.shift = 0;
+        self.bits = 0;
+
+        self.state = match (opcode, extension) {
+            // READ
+            (0b10, _) => {
+                self.out = false;
+                State::Read {
+                    addr: addr.wrapping_sub(1) % EEPROM_WORDS as u8,
+                    bit: 16,
+                }
+            }
+            // WRITE
+            (0b01, _) => State::Data { addr: Some(addr) },
+            // ERASE
+            (0b11, _) => {
+                self.program(Some(addr), 0xFFFF);
+                State::Done
+            }
+            // EWEN
+            (_, 0b11) => {
+                self.write_enabled = true;
+                State::Done
+            }
+            // EWDS
+            (_, 0b00) => {
+                self.write_enabled = false;
+                State::Done
+            }
+            // ERAL
+            (_, 0b10) => {
+                self.program(None, 0xFFFF);
+                State::Done
+            }
+            // WRAL
+            _ => State::Data { addr: None },
+        };
+    }
+
+    // Writes a single word or all of them, if no address is given.
+    fn program(&mut self, addr: Option<u8>, val: u16)
+    {
+        if self.write_enabled {
+            match addr {
+                Some(addr) => self.words[addr as usize] = val,
+                None => self.words.fill(val),
+            }
+        }
+        self.out = true;
+        self.state = State::Done;
+    }
+}
+
+impl Default for Eeprom
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+// Size of the `MX29F008` flash and of the sectors erased at once.
+pub const FLASH_SIZE: usize = 0x100000;
+const SECTOR_SIZE: usize = 0x20000;
+
+// Identification returned in the autoselect mode.
+const MANUFACTURER_ID: u8 = 0xC2;
+const DEVICE_ID: u8 = 0x81;
+
+// Every command is preceded by the unlock sequence, only the lowest 15
+// bits of the address are decoded for it.
+const UNLOCK_ADDR_1: usize = 0x5555;
+const UNLOCK_ADDR_2: usize = 0x2AAA;
+const ADDR_MASK: usize = 0x7FFF;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State
+{
+    Ready,
+    Unlock1,
+    Unlock2,
+    Program,
+    EraseReady,
+    EraseUnlock1,
+    EraseUnlock2,
+    Autoselect,
+}
+
+// Flash memory with the standard `JEDEC` command set. Reads return
+// the stored data unless the chip is in the autoselect mode, writes
+// are interpreted as commands.
+#[derive(Debug)]
+pub struct Flash
+{
+    data: Vec<u8>,
+    state: State,
+    // Guards only the commands which modify the data, the autoselect
+    // and reset ones are accepted anyway.
+    write_enabled: bool,
+}
+
+impl Flash
+{
+    pub fn new() -> Self
+    {
+        Self {
+            data: vec![0xFF; FLASH_SIZE],
+            state: State::Ready,
+            write_enabled: false,
+        }
+    }
+
+    #[inline]
+    pub fn set_write_enabled(&mut self, enabled: bool)
+    {
+        self.write_enabled = enabled;
+    }
+
+    pub fn read(&self, addr: usize) -> u8
+    {
+        let addr = addr % FLASH_SIZE;
+
+        match (self.state, addr & 0xFF) {
+            (State::Autoselect, 0x00) => MANUFACTURER_ID,
+            (State::Autoselect, 0x01) => DEVICE_ID,
+            _ => self.data[addr],
+        }
+    }
+
+    pub fn write(&mut self, addr: usize, val: u8)
+    {
+        let addr = addr % FLASH_SIZE;
+        let command_addr = addr & ADDR_MASK;
+
+        self.state = match (self.state, command_addr, val) {
+            // The reset command works in every state.
+            (_, _, 0xF0) => State::Ready,
+            (State::Ready | State::Autoselect, UNLOCK_ADDR_1, 0xAA) => State::Unlock1,
+            (State::Unlock1, UNLOCK_ADDR_2, 0x55) => State::Unlock2,
+            (State::Unlock2, UNLOCK_ADDR_1, 0xA0) => State::Program,
+            (State::Unlock2, UNLOCK_ADDR_1, 0x80) => State::EraseReady,
+            (State::Unlock2, UNLOCK_ADDR_1, 0x90) => State::Autoselect,
+            // Programming can only clear the bits.
+            (State::Program, _, val) => {
+                if self.write_enabled {
+                    self.data[addr] &= val;
+                }
+                State::Ready
+            }
+            (State::EraseReady, UNLOCK_ADDR_1, 0xAA) => State::EraseUnlock1,
+            (State::EraseUnlock1, UNLOCK_ADDR_2, 0x55) => State::EraseUnlock2,
+            (State::EraseUnlock2, UNLOCK_ADDR_1, 0x10) => {
+                if self.write_enabled {
+                    self.data.fill(0xFF);
+                }
+                State::Ready
+            }
+            (State::EraseUnlock2, _, 0x30) => {
+                if self.write_enabled {
+                    let start = addr - addr % SECTOR_SIZE;
+                    self.data[start..start + SECTOR_SIZE].fill(0xFF);
+                }
+                State::Ready
+            }
+            (State::Autoselect, ..) => State::Autoselect,
+            _ => State::Ready,
+        };
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8]
+    {
+        &self.data
+    }
+
+    pub fn load(&mut self, data: &[u8])
+    {
+        let len = data.len().min(FLASH_SIZE);
+        self.data[..len].copy_from_slice(&data[..len]);
+    }
+}
+
+impl Default for Flash
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::header::Header;
+use crate::cartridge::infrared::InfraredChannel;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RamBanks, RomBanks};
+
+// Value written to 0x0000 - 0x1FFF which maps the infrared port
+// instead of the ram.
+const INFRARED_MODE: u8 = 0x0E;
+
+#[derive(Debug)]
+pub struct HuC1
+{
+    // There is no ram enable, the register switches between the ram
+    // and the infrared port.
+    infrared_mode: bool,
+    // 6-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. The range is [0, 63].
+    rom_bank: u8,
+    // 2-bit register selecting one of the 4 ram banks.
+    ram_bank: u8,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // Ram banks which can be swapped into 0xA000 - 0xBFFF.
+    ram: RamBanks,
+    infrared: Option<Box<dyn InfraredChannel>>,
+}
+
+impl HuC1
+{
+    pub fn new(header: &Header, raw_rom: Vec<u8>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(header.ram_banks);
+
+        Self {
+            infrared_mode: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            rom,
+            ram,
+            infrared: None,
+        }
+    }
+}
+
+impl Mbc for HuC1
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    fn connect_infrared(&mut self, channel: Box<dyn InfraredChannel>)
+    {
+        self.infrared = Some(channel);
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram.load(data);
+    }
+}
+
+impl MemoryAccess for HuC1
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            // Bit 0 is set while the sensor sees the light.
+            0xA000..=0xBFFF if self.infrared_mode => {
+                let receiving = self.infrared.as_ref().is_some_and(|ir| ir.is_receiving());
+                0xC0 | receiving as u8
+            }
+            0xA000..=0xBFFF => self.ram.read_byte(self.ram_bank as usize, addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.infrared_mode = (val & 0x0F) == INFRARED_MODE;
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank = val & 0x3F;
+            }
+            0x4000..=0x5FFF => {
+                self.ram_bank = val & 0x03;
+            }
+            // Bit 0 switches the LED.
+            0xA000..=0xBFFF if self.infrared_mode => {
+                if let Some(ir) = &mut self.infrared {
+                    ir.set_led(val & 0x01 != 0);
+                }
+            }
+            0xA000..=0xBFFF => {
+                self.ram.write_byte(self.ram_bank as usize, addr, val);
+            }
+            _ => {}
+        }
+    }
+}
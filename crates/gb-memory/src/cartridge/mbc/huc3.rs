@@ -0,0 +1,144 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::header::Header;
+use crate::cartridge::infrared::InfraredChannel;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RamBanks, RomBanks};
+use crate::cartridge::rtc::{Clock, HuC3Rtc};
+
+// Values written to 0x0000 - 0x1FFF select what is mapped to
+// 0xA000 - 0xBFFF.
+const MODE_RAM_READ_ONLY: u8 = 0x00;
+const MODE_RAM: u8 = 0x0A;
+const MODE_RTC_COMMAND: u8 = 0x0B;
+const MODE_RTC_RESPONSE: u8 = 0x0C;
+const MODE_RTC_SEMAPHORE: u8 = 0x0D;
+const MODE_INFRARED: u8 = 0x0E;
+
+#[derive(Debug)]
+pub struct HuC3
+{
+    mode: u8,
+    // 7-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. The range is [0, 127].
+    rom_bank: u8,
+    // 2-bit register selecting one of the 4 ram banks.
+    ram_bank: u8,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // Ram banks which can be swapped into 0xA000 - 0xBFFF.
+    ram: RamBanks,
+    rtc: HuC3Rtc,
+    infrared: Option<Box<dyn InfraredChannel>>,
+}
+
+impl HuC3
+{
+    pub fn new(header: &Header, raw_rom: Vec<u8>, clock: Box<dyn Clock>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(header.ram_banks);
+
+        Self {
+            mode: MODE_RAM_READ_ONLY,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            rom,
+            ram,
+            rtc: HuC3Rtc::new(clock),
+            infrared: None,
+        }
+    }
+}
+
+impl Mbc for HuC3
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    fn connect_infrared(&mut self, channel: Box<dyn InfraredChannel>)
+    {
+        self.infrared = Some(channel);
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram.load(data);
+    }
+
+    fn dump_rtc(&mut self) -> Vec<u8>
+    {
+        self.rtc.dump_footer()
+    }
+
+    fn load_rtc(&mut self, data: &[u8]) -> bool
+    {
+        self.rtc.load_footer(data)
+    }
+}
+
+impl MemoryAccess for HuC3
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            0xA000..=0xBFFF => match self.mode {
+                MODE_RAM_READ_ONLY | MODE_RAM => self.ram.read_byte(self.ram_bank as usize, addr),
+                MODE_RTC_RESPONSE => self.rtc.read(),
+                // The commands are executed at once, so the clock is
+                // always ready.
+                MODE_RTC_SEMAPHORE => 0x01,
+                // Bit 0 is set while the sensor sees the light.
+                MODE_INFRARED => {
+                    let receiving = self.infrared.as_ref().is_some_and(|ir| ir.is_receiving());
+                    0xC0 | receiving as u8
+                }
+                _ => 0xFF,
+            },
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.mode = val & 0x0F;
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank = val & 0x7F;
+            }
+            0x4000..=0x5FFF => {
+                self.ram_bank = val & 0x03;
+            }
+            0xA000..=0xBFFF => match self.mode {
+                MODE_RAM => self.ram.write_byte(self.ram_bank as usize, addr, val),
+                MODE_RTC_COMMAND => self.rtc.command(val),
+                // Bit 0 switches the LED.
+                MODE_INFRARED => {
+                    if let Some(ir) = &mut self.infrared {
+                        ir.set_led(val & 0x01 != 0);
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
@@ -2,20 +2,55 @@
 // GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
 
 use crate::MemoryAccess;
+use crate::cartridge::header::Header;
+use crate::cartridge::mbc::Mbc;
 use crate::chunk::MemoryChunk;
 
 #[derive(Debug)]
 pub struct Mbc0
 {
-    rom: MemoryChunk<0x0000, 0x7FFF>,
+    rom: Box<MemoryChunk<0x0000, 0x8000>>,
+    // Optional 8 KiB of ram wired directly to the 0xA000 - 0xBFFF
+    // address space. No licensed game is known to use it.
+    ram: Option<Box<MemoryChunk<0xA000, 0x2000>>>,
 }
 
 impl Mbc0
 {
-    pub fn new(rom: Vec<u8>) -> Self
+    pub fn new(header: &Header, rom: Vec<u8>) -> Self
     {
-        let rom = MemoryChunk::from_vec(rom);
-        Self { rom }
+        // Without a mapper only the first 32 KiB are visible, the rest
+        // is there only when the header does not match the image.
+        let len = rom.len().min(0x8000);
+        let rom = Box::new(MemoryChunk::from_slice(&rom[..len]));
+        let ram = (header.ram_banks > 0).then(Box::default);
+
+        Self { rom, ram }
+    }
+}
+
+impl Mbc for Mbc0
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let start = (addr & 0x4000) as usize;
+        Some(&self.rom.as_slice()[start..start + 0x4000])
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        match &self.ram {
+            Some(ram) => ram.as_slice().to_vec(),
+            None => Vec::new(),
+        }
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        if let Some(ram) = &mut self.ram {
+            let len = data.len().min(ram.as_slice().len());
+            ram.as_mut_slice()[..len].copy_from_slice(&data[..len]);
+        }
     }
 }
 
@@ -24,9 +59,19 @@ impl MemoryAccess for Mbc0
     // `MBC0` allows read-only access to the `ROM` data.
     fn read_byte(&self, addr: u16) -> u8
     {
-        self.rom.read_byte(addr)
+        match (addr, &self.ram) {
+            (0x0000..=0x7FFF, _) => self.rom.read_byte(addr),
+            (0xA000..=0xBFFF, Some(ram)) => ram.read_byte(addr),
+            _ => 0xFF,
+        }
     }
 
-    // `MBC0` does not support any writing operations.
-    fn write_byte(&mut self, _: u16, _: u8) {}
+    // `MBC0` does not support any writing operations, except for
+    // the optional ram.
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        if let (0xA000..=0xBFFF, Some(ram)) = (addr, &mut self.ram) {
+            ram.write_byte(addr, val);
+        }
+    }
 }
@@ -3,101 +3,134 @@
 
 use crate::MemoryAccess;
 use crate::cartridge::header::Header;
-use crate::chunk::MemoryChunk;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RamBanks, RomBanks};
 
-// NOTE: 17.07.2025
-// This will not work for larger cartridges with alternate wiring.
-// Very few games actually used it, so for now let's keep it simple.
+// Multicart collections (`MBC1M`) contain several games, each one of
+// them 256 KiB long and starting with its own header. The second game
+// starts at the bank 0x10, which is where we look for the logo.
+const MULTICART_GAME_BANKS: usize = 0x10;
+const MULTICART_ROM_BANKS: usize = 0x40;
 
 #[derive(Debug)]
 pub struct Mbc1
 {
     ram_enabled: bool,
-    // The banking mode can be in two states. Rom banking means
-    // that 0x0000 - 0x3FFF address space points to the bank 0
-    // of rom and bank 0 of ram. When set to ram banking, the
-    // 0x0000 - 0x3FFF address space can be mapped to different
-    // rom bank and the 0xA000 - 0xBFFF to a different ram bank.
+    // The banking mode can be in two states. In the simple mode
+    // the 0x0000 - 0x3FFF address space always points to the bank 0
+    // of rom and 0xA000 - 0xBFFF to the bank 0 of ram. In the advanced
+    // mode, the secondary bank register is also applied to both
+    // of these areas.
     in_ram_banking_mode: bool,
-    // 5-bit register keeping the rom bank number from the
-    // 0x4000 - 0x7FFF region. The value it can hold can never
+    // 5-bit register keeping the lower bits of the rom bank number
+    // from the 0x4000 - 0x7FFF region. The value it can hold can never
     // be zero. The range is [1, 31].
     rom_lower_rom_bits: u8,
-    // If the 5 bits are not sufficient the secondary bank register
-    // can be used providing additional two bits.
+    // 2-bit secondary bank register. It provides the upper bits of
+    // the rom bank number for cartridges with at least 1 MiB of rom,
+    // or selects the ram bank on cartridges with 32 KiB of ram.
     ram_upper_rom_bits: u8,
-    // First 16 KiB of the cartridge, which is always mapped
-    // to the 0x0000 - 0x3FFF address space.
-    rom_0x: MemoryChunk<0x0000, 0x3FFF>,
-    // Rom banks which can be swapped, all of them are mapped
-    // to the same address space and only once can be accessed
-    // at the same time.
-    rom: Vec<MemoryChunk<0x4000, 0x7FFF>>,
+    // Multicarts do not connect the highest bit of the lower register,
+    // so the secondary register is shifted only by 4 bits.
+    is_multicart: bool,
+    // All rom banks of the cartridge, the bank 0 included.
+    rom: RomBanks,
     // Ram banks which can be swapped, all of them are mapped
     // to the same address space.
-    ram: Vec<MemoryChunk<0xA000, 0xBFFF>>,
+    ram: RamBanks,
 }
 
 impl Mbc1
 {
     pub fn new(header: &Header, raw_rom: Vec<u8>) -> Self
     {
-        const CHUNK: usize = MemoryChunk::<0x0000, 0x3FFF>::SIZE;
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(header.ram_banks);
 
-        let rom_0x = MemoryChunk::from_slice(&raw_rom[..CHUNK]);
-        let ram = (0..header.ram_banks).map(|_| MemoryChunk::new()).collect();
-
-        let rom: Vec<MemoryChunk<16384, 32767>> = raw_rom[CHUNK..]
-            .chunks(CHUNK)
-            .map(MemoryChunk::from_slice)
-            .collect();
-
-        assert_eq!(rom.len() + 1, header.rom_banks);
+        let is_multicart = is_multicart(&rom);
 
         Self {
             ram_enabled: false,
             in_ram_banking_mode: false,
             rom_lower_rom_bits: 0x01,
             ram_upper_rom_bits: 0x00,
-            rom_0x,
+            is_multicart,
             rom,
             ram,
         }
     }
 
     #[inline]
-    fn current_rom_bank(&self) -> usize
+    fn upper_bits_shift(&self) -> u8
+    {
+        match self.is_multicart {
+            true => 4,
+            false => 5,
+        }
+    }
+
+    #[inline]
+    fn lower_rom_bank(&self) -> u8
+    {
+        match self.in_ram_banking_mode {
+            true => self.ram_upper_rom_bits << self.upper_bits_shift(),
+            false => 0x00,
+        }
+    }
+
+    #[inline]
+    fn upper_rom_bank(&self) -> u8
     {
-        let upper_bits = match self.in_ram_banking_mode {
-            true => 0x00,
-            false => self.ram_upper_rom_bits,
+        let lower_bits = match self.is_multicart {
+            true => self.rom_lower_rom_bits & 0x0F,
+            false => self.rom_lower_rom_bits,
         };
-        (self.rom_lower_rom_bits | (upper_bits << 5)) as usize
+        lower_bits | (self.ram_upper_rom_bits << self.upper_bits_shift())
     }
 
     #[inline]
     fn current_ram_bank(&self) -> usize
     {
-        match self.in_ram_banking_mode {
+        // Ram banking is available only on cartridges with 32 KiB of
+        // ram, the smaller ones ignore the secondary register.
+        match self.in_ram_banking_mode && self.ram.len() > 1 {
             true => self.ram_upper_rom_bits as usize,
             false => 0x00,
         }
     }
 }
 
+impl Mbc for Mbc1
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => self.lower_rom_bank(),
+            _ => self.upper_rom_bank(),
+        };
+        Some(self.rom.bank(bank as usize).as_slice())
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram.load(data);
+    }
+}
+
 impl MemoryAccess for Mbc1
 {
     fn read_byte(&self, addr: u16) -> u8
     {
         match addr {
-            0x0000..=0x3FFF => self.rom_0x.read_byte(addr),
-            0x4000..=0x7FFF => {
-                let bank = self.current_rom_bank();
-                self.rom[bank - 1].read_byte(addr)
-            }
+            0x0000..=0x3FFF => self.rom.read_byte(self.lower_rom_bank() as usize, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.upper_rom_bank() as usize, addr),
             0xA000..=0xBFFF if self.ram_enabled => {
-                let bank = self.current_ram_bank();
-                self.ram[bank].read_byte(addr)
+                self.ram.read_byte(self.current_ram_bank(), addr)
             }
             _ => 0xFF,
         }
@@ -110,6 +143,8 @@ impl MemoryAccess for Mbc1
                 self.ram_enabled = (val & 0xF) == 0xA;
             }
             0x2000..=0x3FFF => {
+                // The zero check is done on all 5 bits, even if
+                // the multicart does not use the highest one.
                 self.rom_lower_rom_bits = match val & 0x1F {
                     0 => 1,
                     val => val,
@@ -122,10 +157,18 @@ impl MemoryAccess for Mbc1
                 self.in_ram_banking_mode = (val & 0x01) != 0;
             }
             0xA000..=0xBFFF if self.ram_enabled => {
-                let bank = self.current_ram_bank();
-                self.ram[bank].write_byte(addr, val);
+                self.ram.write_byte(self.current_ram_bank(), addr, val);
             }
             _ => {}
         }
     }
 }
+
+// There is nothing in the header which would tell that the cartridge
+// is a multicart. All known ones are 1 MiB large and contain another
+// valid Nintendo logo in the header of the second game.
+fn is_multicart(rom: &RomBanks) -> bool
+{
+    rom.len() == MULTICART_ROM_BANKS
+        && Header::has_valid_logo(rom.bank(MULTICART_GAME_BANKS).as_slice())
+}
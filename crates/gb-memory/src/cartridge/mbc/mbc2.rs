@@ -0,0 +1,108 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::RomBanks;
+use crate::chunk::MemoryChunk;
+
+#[derive(Debug)]
+pub struct Mbc2
+{
+    ram_enabled: bool,
+    // 4-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. Like in `MBC1` the value can never
+    // be zero. The range is [1, 15].
+    rom_bank: u8,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // The ram is built into the mapper and consists of 512 half-bytes.
+    // Only the lower 9 bits of the address are used, so the whole
+    // 0xA000 - 0xBFFF address space mirrors the same memory.
+    ram: Box<MemoryChunk<0x0000, 0x200>>,
+}
+
+impl Mbc2
+{
+    pub fn new(raw_rom: Vec<u8>) -> Self
+    {
+        Self {
+            ram_enabled: false,
+            rom_bank: 0x01,
+            rom: RomBanks::new(&raw_rom),
+            ram: Box::default(),
+        }
+    }
+
+    #[inline]
+    fn ram_addr(addr: u16) -> u16
+    {
+        addr & 0x01FF
+    }
+}
+
+impl Mbc for Mbc2
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    // Every half-byte is stored as a separate byte, which is the same
+    // layout as used by the other emulators.
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.as_slice().to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        let ram = self.ram.as_mut_slice();
+
+        for (dst, src) in ram.iter_mut().zip(data) {
+            *dst = src & 0x0F;
+        }
+    }
+}
+
+impl MemoryAccess for Mbc2
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            // Only the lower nibble is connected, the upper one is
+            // undefined and reads back as set on the real hardware.
+            0xA000..=0xBFFF if self.ram_enabled => 0xF0 | self.ram.read_byte(Self::ram_addr(addr)),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            // Bit 8 of the address decides which register is written.
+            // When clear the ram is enabled or disabled, when set the
+            // value selects the rom bank.
+            0x0000..=0x3FFF if addr & 0x0100 == 0 => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+            }
+            0x0000..=0x3FFF => {
+                self.rom_bank = match val & 0x0F {
+                    0 => 1,
+                    val => val,
+                };
+            }
+            0xA000..=0xBFFF if self.ram_enabled => {
+                self.ram.write_byte(Self::ram_addr(addr), val & 0x0F);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::header::Header;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RamBanks, RomBanks};
+use crate::cartridge::rtc::{Clock, Rtc};
+
+#[derive(Debug)]
+pub struct Mbc3
+{
+    // Enables both the ram and the rtc registers.
+    ram_enabled: bool,
+    // 7-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. The range is [1, 127], or [1, 255]
+    // on `MBC30`.
+    rom_bank: u8,
+    rom_bank_mask: u8,
+    // Values 0x00 - 0x03 (0x00 - 0x07 on `MBC30`) select one of the
+    // ram banks, while values 0x08 - 0x0C map one of the rtc
+    // registers to 0xA000 - 0xBFFF.
+    ram_bank: u8,
+    max_ram_bank: u8,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // Ram banks which can be swapped into 0xA000 - 0xBFFF.
+    ram: RamBanks,
+    // Only the cartridges with a timer have the clock.
+    rtc: Option<Rtc>,
+}
+
+impl Mbc3
+{
+    pub fn new(header: &Header, raw_rom: Vec<u8>, clock: Box<dyn Clock>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(header.ram_banks);
+
+        let rtc = header.cartridge_type.has_rtc().then(|| Rtc::new(clock));
+
+        Self {
+            ram_enabled: false,
+            rom_bank: 0x01,
+            rom_bank_mask: 0x7F,
+            ram_bank: 0x00,
+            max_ram_bank: 0x03,
+            rom,
+            ram,
+            rtc,
+        }
+    }
+
+    // Variant of the mapper with twice as many rom and ram banks.
+    pub fn mbc30(header: &Header, raw_rom: Vec<u8>, clock: Box<dyn Clock>) -> Self
+    {
+        Self {
+            rom_bank_mask: 0xFF,
+            max_ram_bank: 0x07,
+            ..Self::new(header, raw_rom, clock)
+        }
+    }
+}
+
+impl Mbc for Mbc3
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram.load(data);
+    }
+
+    fn dump_rtc(&mut self) -> Vec<u8>
+    {
+        match &mut self.rtc {
+            Some(rtc) => rtc.dump_footer(),
+            None => Vec::new(),
+        }
+    }
+
+    fn load_rtc(&mut self, data: &[u8]) -> bool
+    {
+        match &mut self.rtc {
+            Some(rtc) => rtc.load_footer(data),
+            None => false,
+        }
+    }
+}
+
+impl MemoryAccess for Mbc3
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            0xA000..=0xBFFF if self.ram_enabled => match (self.ram_bank, &self.rtc) {
+                (bank, _) if bank <= self.max_ram_bank => self.ram.read_byte(bank as usize, addr),
+                (0x08..=0x0C, Some(rtc)) => rtc.read(self.ram_bank),
+                _ => 0xFF,
+            },
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank = match val & self.rom_bank_mask {
+                    0 => 1,
+                    val => val,
+                };
+            }
+            0x4000..=0x5FFF => {
+                self.ram_bank = val & 0x0F;
+            }
+            0x6000..=0x7FFF => {
+                if let Some(rtc) = &mut self.rtc {
+                    rtc.write_latch(val);
+                }
+            }
+            0xA000..=0xBFFF if self.ram_enabled => match (self.ram_bank, &mut self.rtc) {
+                (bank, _) if bank <= self.max_ram_bank => {
+                    self.ram.write_byte(bank as usize, addr, val)
+                }
+                (0x08..=0x0C, Some(rtc)) => rtc.write(self.ram_bank, val),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
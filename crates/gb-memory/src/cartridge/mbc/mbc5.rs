@@ -0,0 +1,117 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::header::Header;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RamBanks, RomBanks};
+
+// Bit of the ram bank register which drives the rumble motor.
+const RUMBLE_BIT: u8 = 0x08;
+
+#[derive(Debug)]
+pub struct Mbc5
+{
+    ram_enabled: bool,
+    // 9-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. Unlike the older mappers, the bank
+    // 0 can be selected as well. The range is [0, 511].
+    rom_bank: u16,
+    // 4-bit register selecting one of the 16 ram banks. On the
+    // cartridges with a motor, bit 3 controls the rumble instead.
+    ram_bank: u8,
+    has_rumble: bool,
+    rumble: bool,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // Ram banks which can be swapped into 0xA000 - 0xBFFF.
+    ram: RamBanks,
+}
+
+impl Mbc5
+{
+    pub fn new(header: &Header, raw_rom: Vec<u8>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(header.ram_banks);
+
+        Self {
+            ram_enabled: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            has_rumble: header.cartridge_type.has_rumble(),
+            rumble: false,
+            rom,
+            ram,
+        }
+    }
+}
+
+impl Mbc for Mbc5
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    fn is_rumbling(&self) -> bool
+    {
+        self.rumble
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram.load(data);
+    }
+}
+
+impl MemoryAccess for Mbc5
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            0xA000..=0xBFFF if self.ram_enabled => self.ram.read_byte(self.ram_bank as usize, addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+            }
+            // The lower 8 bits of the rom bank number.
+            0x2000..=0x2FFF => {
+                self.rom_bank = (self.rom_bank & 0x100) | val as u16;
+            }
+            // The 9th bit of the rom bank number.
+            0x3000..=0x3FFF => {
+                self.rom_bank = (self.rom_bank & 0xFF) | (((val & 0x01) as u16) << 8);
+            }
+            0x4000..=0x5FFF if self.has_rumble => {
+                self.rumble = val & RUMBLE_BIT != 0;
+                self.ram_bank = val & 0x07;
+            }
+            0x4000..=0x5FFF => {
+                self.ram_bank = val & 0x0F;
+            }
+            0xA000..=0xBFFF if self.ram_enabled => {
+                self.ram.write_byte(self.ram_bank as usize, addr, val);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::RomBanks;
+use crate::cartridge::mbc::flash::Flash;
+
+// Both the rom and the ram are switched in two independent halves.
+const ROM_HALF_SIZE: usize = 0x2000;
+const RAM_HALF_SIZE: usize = 0x1000;
+const RAM_SIZE: usize = 0x8000;
+
+// Value of the rom/flash select register which maps the flash.
+const FLASH_SELECT_BIT: u8 = 0x08;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Half
+{
+    // 7-bit number of the 8 KiB rom or flash bank.
+    rom_bank: u8,
+    use_flash: bool,
+    // 3-bit number of the 4 KiB ram bank.
+    ram_bank: u8,
+}
+
+#[derive(Debug)]
+pub struct Mbc6
+{
+    ram_enabled: bool,
+    flash_enabled: bool,
+    // Halves mapped to 0x4000 - 0x5FFF and 0xA000 - 0xAFFF, and to
+    // 0x6000 - 0x7FFF and 0xB000 - 0xBFFF.
+    halves: [Half; 2],
+    // All rom banks of the cartridge, the first 16 KiB are always
+    // mapped to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    ram: Vec<u8>,
+    flash: Flash,
+}
+
+impl Mbc6
+{
+    pub fn new(raw_rom: Vec<u8>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+
+        Self {
+            ram_enabled: false,
+            flash_enabled: false,
+            halves: [Half::default(); 2],
+            rom,
+            ram: vec![0x00; RAM_SIZE],
+            flash: Flash::new(),
+        }
+    }
+
+    // Address 0x4000 - 0x7FFF translated to the offset in the rom or
+    // the flash, depending on what the half maps.
+    fn rom_offset(&self, addr: u16) -> (Half, usize)
+    {
+        let half = self.halves[((addr >> 13) & 0x01) as usize];
+        let offset = half.rom_bank as usize * ROM_HALF_SIZE + (addr as usize & 0x1FFF);
+
+        (half, offset)
+    }
+
+    fn ram_offset(&self, addr: u16) -> usize
+    {
+        let half = self.halves[((addr >> 12) & 0x01) as usize];
+        (half.ram_bank as usize * RAM_HALF_SIZE + (addr as usize & 0x0FFF)) % RAM_SIZE
+    }
+}
+
+impl Mbc for Mbc6
+{
+    // The rom is switched in 8 KiB halves, which the page table does
+    // not support, so every read goes through `read_byte`.
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        match addr {
+            0x0000..=0x3FFF => Some(self.rom.bank(0x00).as_slice()),
+            _ => None,
+        }
+    }
+
+    // Writes to the flash are persisted as well.
+    fn is_save_write(&self, addr: u16) -> bool
+    {
+        match addr {
+            0x4000..=0x7FFF => self.rom_offset(addr).0.use_flash,
+            0xA000..=0xBFFF => true,
+            _ => false,
+        }
+    }
+
+    // The flash is stored right after the ram.
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        let mut data = self.ram.clone();
+        data.extend_from_slice(self.flash.as_slice());
+
+        data
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        let len = data.len().min(RAM_SIZE);
+        self.ram[..len].copy_from_slice(&data[..len]);
+
+        if data.len() > RAM_SIZE {
+            self.flash.load(&data[RAM_SIZE..]);
+        }
+    }
+}
+
+impl MemoryAccess for Mbc6
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => match self.rom_offset(addr) {
+                (half, offset) if half.use_flash && self.flash_enabled => self.flash.read(offset),
+                (half, _) if half.use_flash => 0xFF,
+                (_, offset) => {
+                    let bank = offset / (2 * ROM_HALF_SIZE);
+                    self.rom.read_byte(bank, (offset & 0x3FFF) as u16)
+                }
+            },
+            0xA000..=0xBFFF if self.ram_enabled => self.ram[self.ram_offset(addr)],
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x03FF => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+            }
+            0x0400..=0x07FF => {
+                self.halves[0].ram_bank = val & 0x07;
+            }
+            0x0800..=0x0BFF => {
+                self.halves[1].ram_bank = val & 0x07;
+            }
+            0x0C00..=0x0FFF => {
+                self.flash_enabled = val & 0x01 != 0;
+            }
+            0x1000 => {
+                self.flash.set_write_enabled(val & 0x01 != 0);
+            }
+            0x2000..=0x27FF => {
+                self.halves[0].rom_bank = val & 0x7F;
+            }
+            0x2800..=0x2FFF => {
+                self.halves[0].use_flash = val & FLASH_SELECT_BIT != 0;
+            }
+            0x3000..=0x37FF => {
+                self.halves[1].rom_bank = val & 0x7F;
+            }
+            0x3800..=0x3FFF => {
+                self.halves[1].use_flash = val & FLASH_SELECT_BIT != 0;
+            }
+            0x4000..=0x7FFF => {
+                let (half, offset) = self.rom_offset(addr);
+
+                if half.use_flash && self.flash_enabled {
+                    self.flash.write(offset, val);
+                }
+            }
+            0xA000..=0xBFFF if self.ram_enabled => {
+                let offset = self.ram_offset(addr);
+                self.ram[offset] = val;
+            }
+            _ => {}
+        }
+    }
+}
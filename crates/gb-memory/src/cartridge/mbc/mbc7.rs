@@ -0,0 +1,157 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::RomBanks;
+use crate::cartridge::mbc::eeprom::Eeprom;
+
+// Value reported by the accelerometer when the console lies flat, and
+// the change of the value caused by the acceleration of 1 g.
+const ACCELEROMETER_CENTER: u16 = 0x81D0;
+const ACCELEROMETER_GRAVITY: f32 = 112.0;
+// Value of the latch after it was erased, before the next sample.
+const ACCELEROMETER_ERASED: u16 = 0x8000;
+
+const ERASE_COMMAND: u8 = 0x55;
+const LATCH_COMMAND: u8 = 0xAA;
+const SECOND_RAM_ENABLE: u8 = 0x40;
+
+#[derive(Debug)]
+pub struct Mbc7
+{
+    // The registers are accessible only if both of the enable
+    // registers hold the right values.
+    ram_enabled: bool,
+    ram_enabled_2: bool,
+    // 7-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. The range is [0, 127].
+    rom_bank: u8,
+    // Tilt set by the front-end, in g on both axes.
+    tilt: (f32, f32),
+    // Sample of the accelerometer taken by the latch command.
+    latched: (u16, u16),
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // Instead of the ram, the cartridge keeps the save in the eeprom.
+    eeprom: Eeprom,
+}
+
+impl Mbc7
+{
+    pub fn new(raw_rom: Vec<u8>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+
+        Self {
+            ram_enabled: false,
+            ram_enabled_2: false,
+            rom_bank: 0x01,
+            tilt: (0.0, 0.0),
+            latched: (ACCELEROMETER_ERASED, ACCELEROMETER_ERASED),
+            rom,
+            eeprom: Eeprom::new(),
+        }
+    }
+
+    fn sample(tilt: f32) -> u16
+    {
+        let offset = (tilt * ACCELEROMETER_GRAVITY) as i32;
+        (ACCELEROMETER_CENTER as i32 + offset).clamp(0, u16::MAX as i32) as u16
+    }
+
+    // Registers are mirrored across 0xA000 - 0xAFFF, bits 4 - 7 of
+    // the address select one of them.
+    fn read_register(&self, addr: u16) -> u8
+    {
+        let (x, y) = self.latched;
+
+        match (addr >> 4) & 0x0F {
+            0x02 => x as u8,
+            0x03 => (x >> 8) as u8,
+            0x04 => y as u8,
+            0x05 => (y >> 8) as u8,
+            0x06 => 0x00,
+            0x08 => self.eeprom.read(),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_register(&mut self, addr: u16, val: u8)
+    {
+        match ((addr >> 4) & 0x0F, val) {
+            (0x00, ERASE_COMMAND) => {
+                self.latched = (ACCELEROMETER_ERASED, ACCELEROMETER_ERASED);
+            }
+            // A new sample can be latched only after the old one was
+            // erased.
+            (0x01, LATCH_COMMAND)
+                if self.latched == (ACCELEROMETER_ERASED, ACCELEROMETER_ERASED) =>
+            {
+                self.latched = (Self::sample(self.tilt.0), Self::sample(self.tilt.1));
+            }
+            (0x08, val) => self.eeprom.write(val),
+            _ => {}
+        }
+    }
+}
+
+impl Mbc for Mbc7
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    fn set_tilt(&mut self, x: f32, y: f32)
+    {
+        self.tilt = (x, y);
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.eeprom.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.eeprom.load(data);
+    }
+}
+
+impl MemoryAccess for Mbc7
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            0xA000..=0xAFFF if self.ram_enabled && self.ram_enabled_2 => self.read_register(addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank = val & 0x7F;
+            }
+            0x4000..=0x5FFF => {
+                self.ram_enabled_2 = val == SECOND_RAM_ENABLE;
+            }
+            0xA000..=0xAFFF if self.ram_enabled && self.ram_enabled_2 => {
+                self.write_register(addr, val);
+            }
+            _ => {}
+        }
+    }
+}
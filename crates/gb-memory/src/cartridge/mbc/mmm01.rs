@@ -0,0 +1,180 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::header::Header;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RamBanks, RomBanks};
+
+// Bit of the ram enable register which locks the game window.
+const MAP_ENABLE_BIT: u8 = 0x40;
+// Banks mapped before the game is selected, the last 32 KiB of the
+// rom where the menu lives. They wrap to the size of the rom.
+const MENU_BANKS: (usize, usize) = (0x1FE, 0x1FF);
+
+// Multicart mapper, which starts in the unmapped mode with the menu
+// visible. The menu selects the rom and ram window of the game and
+// locks it, from then on the game sees an `MBC1` which can only switch
+// the banks inside of its window.
+#[derive(Debug)]
+pub struct Mmm01
+{
+    mapped: bool,
+    ram_enabled: bool,
+    // The rom bank number is split into 3 registers, bits 0 - 4,
+    // 5 - 6 and 7 - 8. Only the lowest one stays writable once the
+    // game is mapped, except for the bits fixed by the mask.
+    rom_bank_low: u8,
+    rom_bank_mid: u8,
+    rom_bank_high: u8,
+    rom_bank_mask: u8,
+    // The ram bank number is split into 2 registers, bits 0 - 1 and
+    // 2 - 3, the mask works the same way as for the rom.
+    ram_bank_low: u8,
+    ram_bank_high: u8,
+    ram_bank_mask: u8,
+    in_ram_banking_mode: bool,
+    mode_locked: bool,
+    // All rom banks of the cartridge, every game occupies a window
+    // of them.
+    rom: RomBanks,
+    // Ram banks which can be swapped into 0xA000 - 0xBFFF.
+    ram: RamBanks,
+}
+
+impl Mmm01
+{
+    pub fn new(header: &Header, raw_rom: Vec<u8>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(header.ram_banks);
+
+        Self {
+            mapped: false,
+            ram_enabled: false,
+            rom_bank_low: 0x01,
+            rom_bank_mid: 0x00,
+            rom_bank_high: 0x00,
+            rom_bank_mask: 0x00,
+            ram_bank_low: 0x00,
+            ram_bank_high: 0x00,
+            ram_bank_mask: 0x00,
+            in_ram_banking_mode: false,
+            mode_locked: false,
+            rom,
+            ram,
+        }
+    }
+
+    fn rom_bank(&self, addr: u16) -> usize
+    {
+        if !self.mapped {
+            return match addr {
+                0x0000..=0x3FFF => MENU_BANKS.0,
+                _ => MENU_BANKS.1,
+            };
+        }
+        let base = (self.rom_bank_high as usize) << 7 | (self.rom_bank_mid as usize) << 5;
+
+        // Like on `MBC1`, the bank 0 can not be mapped to the upper
+        // area, but only the bits outside of the mask are checked.
+        let low = match addr {
+            0x0000..=0x3FFF => self.rom_bank_low & self.rom_bank_mask,
+            _ if self.rom_bank_low & !self.rom_bank_mask == 0 => self.rom_bank_low | 0x01,
+            _ => self.rom_bank_low,
+        };
+        base | low as usize
+    }
+
+    // Like on `MBC1`, the ram bank can be switched only in the ram
+    // banking mode, otherwise the bits outside of the mask are zero.
+    fn ram_bank(&self) -> usize
+    {
+        let low = match self.in_ram_banking_mode {
+            true => self.ram_bank_low,
+            false => self.ram_bank_low & self.ram_bank_mask,
+        };
+        ((self.ram_bank_high << 2) | low) as usize
+    }
+
+    // Once the game is mapped, the masked bits keep their values.
+    fn masked(&self, old: u8, new: u8, mask: u8) -> u8
+    {
+        match self.mapped {
+            true => (old & mask) | (new & !mask),
+            false => new,
+        }
+    }
+}
+
+impl Mbc for Mmm01
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        Some(self.rom.bank(self.rom_bank(addr)).as_slice())
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram.load(data);
+    }
+}
+
+impl MemoryAccess for Mmm01
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x7FFF => self.rom.read_byte(self.rom_bank(addr), addr),
+            0xA000..=0xBFFF if self.ram_enabled => self.ram.read_byte(self.ram_bank(), addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+
+                if !self.mapped {
+                    self.ram_bank_mask = (val >> 4) & 0x03;
+                    self.mapped = val & MAP_ENABLE_BIT != 0;
+                }
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank_low = self.masked(self.rom_bank_low, val & 0x1F, self.rom_bank_mask);
+
+                if !self.mapped {
+                    self.rom_bank_mid = (val >> 5) & 0x03;
+                }
+            }
+            0x4000..=0x5FFF => {
+                self.ram_bank_low = self.masked(self.ram_bank_low, val & 0x03, self.ram_bank_mask);
+
+                if !self.mapped {
+                    self.ram_bank_high = (val >> 2) & 0x03;
+                    self.rom_bank_high = (val >> 4) & 0x03;
+                    self.mode_locked = val & 0x40 != 0;
+                }
+            }
+            0x6000..=0x7FFF => {
+                if !self.mode_locked {
+                    self.in_ram_banking_mode = val & 0x01 != 0;
+                }
+                if !self.mapped {
+                    self.rom_bank_mask = (val >> 1) & 0x1E;
+                }
+            }
+            0xA000..=0xBFFF if self.ram_enabled => {
+                self.ram.write_byte(self.ram_bank(), addr, val);
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,5 +1,102 @@
 // Copyright: (c) 2025, Wiktor Nowak
 // GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
 
+pub mod banks;
+pub mod eeprom;
+pub mod flash;
+pub mod huc1;
+pub mod huc3;
 pub mod mbc0;
 pub mod mbc1;
+pub mod mbc2;
+pub mod mbc3;
+pub mod mbc5;
+pub mod mbc6;
+pub mod mbc7;
+pub mod mmm01;
+pub mod pocket_camera;
+pub mod sachen;
+pub mod tama5;
+pub mod wisdom_tree;
+
+use std::fmt;
+
+use crate::MemoryAccess;
+use crate::cartridge::camera::CameraImage;
+use crate::cartridge::infrared::InfraredChannel;
+
+pub trait Mbc: MemoryAccess + Send + fmt::Debug
+{
+    // Returns the 16 KiB rom bank which is currently visible in the
+    // area containing the given address (0x0000 - 0x3FFF or 0x4000 -
+    // 0x7FFF). The slice is used to map the bank directly into the
+    // page table, so it must not move while the mapping is active. It
+    // has to live in a separate heap allocation (e.g. a `Vec`), since
+    // the mapper itself moves together with the bus. Mappers which can
+    // not provide a plain slice return `None` and every read goes
+    // through `read_byte` instead.
+    fn mapped_rom(&self, _addr: u16) -> Option<&[u8]>
+    {
+        None
+    }
+
+    // State of the rumble motor, only cartridges with a motor can
+    // ever turn it on.
+    fn is_rumbling(&self) -> bool
+    {
+        false
+    }
+
+    // Tilt of the console in g, reported by the accelerometer of the
+    // cartridges with a motion sensor. Positive values mean the right
+    // and the bottom side of the console are lowered.
+    fn set_tilt(&mut self, _x: f32, _y: f32) {}
+
+    // Picture seen by the sensor of the Pocket Camera, used by every
+    // capture until it is replaced.
+    fn set_camera_image(&mut self, _image: CameraImage) {}
+
+    // Infrared LED and sensor of the HuC1 and HuC3 cartridges. Without
+    // a channel the sensor never sees any light.
+    fn connect_infrared(&mut self, _channel: Box<dyn InfraredChannel>) {}
+
+    // Returns true if the write to the given address can change what
+    // `mapped_rom` returns, the bus remaps the rom only after these.
+    fn is_bank_write(&self, addr: u16) -> bool
+    {
+        matches!(addr, 0x0000..=0x7FFF)
+    }
+
+    // Returns true if the write to the given address can modify the
+    // data which is persisted in the save file.
+    fn is_save_write(&self, addr: u16) -> bool
+    {
+        matches!(addr, 0xA000..=0xBFFF)
+    }
+
+    // Content of the external ram in the raw layout used by the `.sav`
+    // files of other emulators, which is all of the banks one after
+    // another.
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        Vec::new()
+    }
+
+    // Restores the external ram from the data returned by `dump_ram`.
+    // Data which does not fit into the ram is ignored.
+    fn load_ram(&mut self, _data: &[u8]) {}
+
+    // State of the real-time clock, appended to the save file after
+    // the ram. Empty for the cartridges without a clock.
+    fn dump_rtc(&mut self) -> Vec<u8>
+    {
+        Vec::new()
+    }
+
+    // Restores the clock from the data returned by `dump_rtc`. Returns
+    // false if the data is not recognized.
+    fn load_rtc(&mut self, _data: &[u8]) -> bool
+    {
+        false
+    }
+}
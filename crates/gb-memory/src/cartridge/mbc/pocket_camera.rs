@@ -0,0 +1,128 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::camera::{CameraImage, IMAGE_OFFSET, Sensor};
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::{RAM_BANK_SIZE, RamBanks, RomBanks};
+
+const RAM_BANKS: usize = 16;
+// Bit of the ram bank register which maps the sensor registers.
+const SENSOR_BIT: u8 = 0x10;
+
+#[derive(Debug)]
+pub struct PocketCamera
+{
+    // Unlike the other mappers, the ram can be read even if it is
+    // disabled, only the writes are blocked.
+    ram_enabled: bool,
+    // 6-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. The range is [0, 63].
+    rom_bank: u8,
+    // Values 0x00 - 0x0F select one of the ram banks, while setting
+    // bit 4 maps the sensor registers to 0xA000 - 0xBFFF.
+    ram_bank: u8,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    // Ram banks which can be swapped into 0xA000 - 0xBFFF. The last
+    // picture taken by the sensor is stored in the first one.
+    ram: RamBanks,
+    sensor: Sensor,
+}
+
+impl PocketCamera
+{
+    pub fn new(raw_rom: Vec<u8>) -> Self
+    {
+        let rom = RomBanks::new(&raw_rom);
+        let ram = RamBanks::new(RAM_BANKS);
+
+        Self {
+            ram_enabled: false,
+            rom_bank: 0x01,
+            ram_bank: 0x00,
+            rom,
+            ram,
+            sensor: Sensor::new(),
+        }
+    }
+
+    fn capture(&mut self)
+    {
+        let image = self.sensor.capture();
+
+        for (offset, byte) in image.iter().enumerate() {
+            let addr = 0xA000 + (IMAGE_OFFSET + offset) as u16;
+            self.ram.write_byte(0x00, addr, *byte);
+        }
+    }
+}
+
+impl Mbc for PocketCamera
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank as usize,
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    fn set_camera_image(&mut self, image: CameraImage)
+    {
+        self.sensor.set_image(image);
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.dump()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        self.ram
+            .load(&data[..data.len().min(RAM_BANKS * RAM_BANK_SIZE)]);
+    }
+}
+
+impl MemoryAccess for PocketCamera
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank as usize, addr),
+            0xA000..=0xBFFF if self.ram_bank & SENSOR_BIT != 0 => self.sensor.read(addr),
+            0xA000..=0xBFFF => self.ram.read_byte(self.ram_bank as usize, addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF => {
+                self.ram_enabled = (val & 0xF) == 0xA;
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank = val & 0x3F;
+            }
+            0x4000..=0x5FFF => {
+                self.ram_bank = val & 0x1F;
+            }
+            0xA000..=0xBFFF if self.ram_bank & SENSOR_BIT != 0 => {
+                self.sensor.write(addr, val);
+
+                if self.sensor.is_busy() {
+                    self.capture();
+                }
+            }
+            0xA000..=0xBFFF if self.ram_enabled => {
+                self.ram.write_byte(self.ram_bank as usize, addr, val);
+            }
+            _ => {}
+        }
+    }
+}
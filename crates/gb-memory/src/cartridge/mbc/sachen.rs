@@ -0,0 +1,121 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::cell::Cell;
+
+use crate::MemoryAccess;
+use crate::cartridge::detection::sachen_unscramble;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::RomBanks;
+
+// The base bank and the mask can be changed only as long as both of
+// these bits of the base bank are set, which lets the multicart menus
+// lock the game into its window.
+const UNLOCKED_BASE_BITS: u8 = 0x30;
+
+// Entry point of the game, the boot rom jumps here once it is done.
+const ENTRY_POINT: u16 = 0x0100;
+
+// NOTE: 19.10.2025
+// The Sachen MMC1 and MMC2 differ only in the way they get unlocked
+// while the boot rom is running (MMC2 has to pass the check of both
+// the `DMG` and the `CGB` boot rom). The boot rom is not emulated, so
+// both of them unlock on the first read from the entry point, which
+// is where the boot rom hands over to the game. Until then the header
+// area reads the same as for the boot rom.
+#[derive(Debug)]
+pub struct Sachen
+{
+    // The bits of the bank number set in the mask are taken from the
+    // base bank, which is also mapped to the 0x0000 - 0x3FFF area.
+    base_bank: u8,
+    mask: u8,
+    // 8-bit register keeping the rom bank number from the
+    // 0x4000 - 0x7FFF region. The range is [1, 255].
+    rom_bank: u8,
+    // Cleared by the first read from the entry point, which happens
+    // behind a shared reference.
+    locked: Cell<bool>,
+    rom: RomBanks,
+}
+
+impl Sachen
+{
+    pub fn new(raw_rom: Vec<u8>) -> Self
+    {
+        Self {
+            base_bank: 0xFF,
+            mask: 0x00,
+            rom_bank: 0x01,
+            locked: Cell::new(true),
+            rom: RomBanks::new(&raw_rom),
+        }
+    }
+
+    fn rom_bank(&self, addr: u16) -> usize
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => self.base_bank & self.mask,
+            _ => (self.rom_bank & !self.mask) | (self.base_bank & self.mask),
+        };
+        bank as usize
+    }
+
+    #[inline]
+    fn can_change_base(&self) -> bool
+    {
+        self.base_bank & UNLOCKED_BASE_BITS == UNLOCKED_BASE_BITS
+    }
+}
+
+impl Mbc for Sachen
+{
+    // The header area is scrambled, so the bank 0 can not be mapped
+    // directly.
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        match addr {
+            0x0000..=0x3FFF => None,
+            _ => Some(self.rom.bank(self.rom_bank(addr)).as_slice()),
+        }
+    }
+}
+
+impl MemoryAccess for Sachen
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        if addr == ENTRY_POINT {
+            self.locked.set(false);
+        }
+        match addr {
+            // While locked, the address lines are swapped and the line 7
+            // is set, so the boot rom sees the Nintendo logo.
+            0x0100..=0x01FF if self.locked.get() => {
+                let addr = sachen_unscramble(addr as usize | 0x80) as u16;
+                self.rom.read_byte(self.rom_bank(addr), addr)
+            }
+            0x0000..=0x7FFF => self.rom.read_byte(self.rom_bank(addr), addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0x0000..=0x1FFF if self.can_change_base() => {
+                self.base_bank = val;
+            }
+            0x2000..=0x3FFF => {
+                self.rom_bank = match val {
+                    0 => 1,
+                    val => val,
+                };
+            }
+            0x4000..=0x5FFF if self.can_change_base() => {
+                self.mask = val;
+            }
+            _ => {}
+        }
+    }
+}
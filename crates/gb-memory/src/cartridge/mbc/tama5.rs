@@ -0,0 +1,199 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::RomBanks;
+use crate::cartridge::rtc::{Clock, Rtc};
+
+// The cartridge keeps its save in 32 bytes of memory inside of the
+// `TAMA5`, addressed with 5 bits.
+const RAM_SIZE: usize = 0x20;
+
+// Registers selected by writing to 0xA001. Every one of them holds
+// a single nibble written to 0xA000.
+const REG_ROM_BANK_LOW: u8 = 0x00;
+const REG_ROM_BANK_HIGH: u8 = 0x01;
+const REG_VALUE_LOW: u8 = 0x04;
+const REG_VALUE_HIGH: u8 = 0x05;
+// Bit 0 is the highest bit of the address, bits 1 - 3 the command.
+const REG_COMMAND: u8 = 0x06;
+// Writing the lower nibble of the address executes the command.
+const REG_ADDRESS: u8 = 0x07;
+const REG_COUNT: usize = 0x08;
+// Read only registers.
+const REG_ACTIVE: u8 = 0x0A;
+const REG_RESULT_LOW: u8 = 0x0C;
+const REG_RESULT_HIGH: u8 = 0x0D;
+
+const COMMAND_RAM_WRITE: u8 = 0x00;
+const COMMAND_RAM_READ: u8 = 0x01;
+const COMMAND_CLOCK: u8 = 0x02;
+
+// Addresses of the clock command. The time is exchanged in `BCD`.
+const CLOCK_MINUTES_WRITE: u8 = 0x04;
+const CLOCK_HOURS_WRITE: u8 = 0x05;
+const CLOCK_MINUTES_READ: u8 = 0x06;
+const CLOCK_HOURS_READ: u8 = 0x07;
+
+// Value returned while the chip is ready to accept the commands.
+const READY: u8 = 0xF1;
+
+// Bandai mapper used by Tamagotchi 3. Everything is accessed through
+// two addresses, 0xA001 selects one of the nibble-wide registers and
+// 0xA000 reads or writes it. The clock is counted by the same `Rtc` as
+// on `MBC3`, so the time is persisted in the same footer.
+#[derive(Debug)]
+pub struct Tama5
+{
+    reg: u8,
+    registers: [u8; REG_COUNT],
+    // Result of the last read command.
+    result: u8,
+    // All rom banks of the cartridge, the bank 0 is always mapped
+    // to the 0x0000 - 0x3FFF address space.
+    rom: RomBanks,
+    ram: [u8; RAM_SIZE],
+    rtc: Rtc,
+}
+
+impl Tama5
+{
+    pub fn new(raw_rom: Vec<u8>, clock: Box<dyn Clock>) -> Self
+    {
+        Self {
+            reg: 0x00,
+            registers: [0x00; REG_COUNT],
+            result: 0x00,
+            rom: RomBanks::new(&raw_rom),
+            ram: [0x00; RAM_SIZE],
+            rtc: Rtc::new(clock),
+        }
+    }
+
+    // Combines two of the nibble-wide registers.
+    #[inline]
+    fn byte(&self, high: u8, low: u8) -> u8
+    {
+        (self.registers[high as usize] << 4) | self.registers[low as usize]
+    }
+
+    #[inline]
+    fn rom_bank(&self) -> usize
+    {
+        self.byte(REG_ROM_BANK_HIGH, REG_ROM_BANK_LOW) as usize
+    }
+
+    fn execute(&mut self)
+    {
+        let command = self.registers[REG_COMMAND as usize];
+        let addr = ((command & 0x01) << 4) | self.registers[REG_ADDRESS as usize];
+        let value = self.byte(REG_VALUE_HIGH, REG_VALUE_LOW);
+
+        match command >> 1 {
+            COMMAND_RAM_WRITE => self.ram[addr as usize] = value,
+            COMMAND_RAM_READ => self.result = self.ram[addr as usize],
+            COMMAND_CLOCK => self.clock_command(addr, value),
+            _ => {}
+        }
+    }
+
+    fn clock_command(&mut self, addr: u8, value: u8)
+    {
+        self.rtc.latch();
+
+        match addr {
+            CLOCK_MINUTES_WRITE => self.rtc.write(0x09, from_bcd(value)),
+            CLOCK_HOURS_WRITE => self.rtc.write(0x0A, from_bcd(value)),
+            CLOCK_MINUTES_READ => self.result = to_bcd(self.rtc.read(0x09)),
+            CLOCK_HOURS_READ => self.result = to_bcd(self.rtc.read(0x0A)),
+            _ => {}
+        }
+    }
+}
+
+#[inline]
+fn to_bcd(val: u8) -> u8
+{
+    ((val / 10) << 4) | (val % 10)
+}
+
+#[inline]
+fn from_bcd(val: u8) -> u8
+{
+    (val >> 4) * 10 + (val & 0x0F)
+}
+
+impl Mbc for Tama5
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        let bank = match addr {
+            0x0000..=0x3FFF => 0x00,
+            _ => self.rom_bank(),
+        };
+        Some(self.rom.bank(bank).as_slice())
+    }
+
+    // The rom bank is selected through the registers at 0xA000.
+    fn is_bank_write(&self, addr: u16) -> bool
+    {
+        matches!(addr, 0x0000..=0x7FFF | 0xA000)
+    }
+
+    fn dump_ram(&self) -> Vec<u8>
+    {
+        self.ram.to_vec()
+    }
+
+    fn load_ram(&mut self, data: &[u8])
+    {
+        let len = data.len().min(RAM_SIZE);
+        self.ram[..len].copy_from_slice(&data[..len]);
+    }
+
+    fn dump_rtc(&mut self) -> Vec<u8>
+    {
+        self.rtc.dump_footer()
+    }
+
+    fn load_rtc(&mut self, data: &[u8]) -> bool
+    {
+        self.rtc.load_footer(data)
+    }
+}
+
+impl MemoryAccess for Tama5
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x3FFF => self.rom.read_byte(0x00, addr),
+            0x4000..=0x7FFF => self.rom.read_byte(self.rom_bank(), addr),
+            0xA000 => match self.reg {
+                REG_ACTIVE => READY,
+                REG_RESULT_LOW => 0xF0 | (self.result & 0x0F),
+                REG_RESULT_HIGH => 0xF0 | (self.result >> 4),
+                _ => READY,
+            },
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        match addr {
+            0xA000 if (self.reg as usize) < REG_COUNT => {
+                self.registers[self.reg as usize] = val & 0x0F;
+
+                if self.reg == REG_ADDRESS {
+                    self.execute();
+                }
+            }
+            0xA001 => {
+                self.reg = val & 0x0F;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::MemoryAccess;
+use crate::cartridge::mbc::Mbc;
+use crate::cartridge::mbc::banks::RomBanks;
+
+// Unlicensed mapper which switches the whole 0x0000 - 0x7FFF area at
+// once. The bank is selected by the lower byte of the address written
+// to, the value itself is ignored.
+#[derive(Debug)]
+pub struct WisdomTree
+{
+    // Number of the 32 KiB bank, which spans two regular rom banks.
+    bank: u8,
+    rom: RomBanks,
+}
+
+impl WisdomTree
+{
+    pub fn new(raw_rom: Vec<u8>) -> Self
+    {
+        Self {
+            bank: 0x00,
+            rom: RomBanks::new(&raw_rom),
+        }
+    }
+
+    fn rom_bank(&self, addr: u16) -> usize
+    {
+        self.bank as usize * 2 + (addr as usize >> 14)
+    }
+}
+
+impl Mbc for WisdomTree
+{
+    fn mapped_rom(&self, addr: u16) -> Option<&[u8]>
+    {
+        Some(self.rom.bank(self.rom_bank(addr)).as_slice())
+    }
+}
+
+impl MemoryAccess for WisdomTree
+{
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match addr {
+            0x0000..=0x7FFF => self.rom.read_byte(self.rom_bank(addr), addr),
+            _ => 0xFF,
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, _val: u8)
+    {
+        if let 0x0000..=0x3FFF = addr {
+            self.bank = addr as u8;
+        }
+    }
+}
@@ -1,7 +1,19 @@
 // Copyright: (c) 2025, Wiktor Nowak
 // GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
 
+pub mod archive;
 pub mod builder;
+pub mod camera;
+pub mod database;
+pub mod detection;
 pub mod errors;
+pub mod hash;
 pub mod header;
+pub mod infrared;
+pub mod licensee;
 pub mod mbc;
+pub mod options;
+pub mod patch;
+pub mod rtc;
+pub mod save;
+pub mod validation;
@@ -0,0 +1,49 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use crate::cartridge::header::{CgbFlag, Header, Mapper};
+
+// Values forced by the user instead of the ones read from the header.
+// Homebrew roms often ship with wrong header bytes, this allows to play
+// and test them anyway. Everything left as `None` is taken from the
+// header or detected.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CartridgeOptions
+{
+    pub mapper: Option<Mapper>,
+    // The image is padded or trimmed to exactly this many banks.
+    pub rom_banks: Option<usize>,
+    pub ram_banks: Option<usize>,
+    // Decides whether the ram is persisted in the save file.
+    pub battery: Option<bool>,
+    // `CgbFlag::DmgOnly` forces the `DMG` mode and `CgbFlag::CgbOnly`
+    // the `CGB` one.
+    pub cgb: Option<CgbFlag>,
+}
+
+impl CartridgeOptions
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // Overwrites the fields of the header which are not derived from
+    // the size of the image.
+    pub fn apply(&self, header: &mut Header)
+    {
+        if let Some(ram_banks) = self.ram_banks {
+            header.ram_banks = ram_banks;
+        }
+        if let Some(cgb) = self.cgb {
+            header.cgb = cgb;
+        }
+    }
+
+    #[inline]
+    pub fn has_battery(&self, header: &Header) -> bool
+    {
+        self.battery
+            .unwrap_or_else(|| header.cartridge_type.has_battery())
+    }
+}
@@ -0,0 +1,389 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fs;
+use std::path::Path;
+
+use crate::cartridge::errors::CartridgeError;
+use crate::cartridge::hash::crc32;
+use crate::cartridge::mbc::banks::{MAX_ROM_BANKS, ROM_BANK_SIZE};
+
+const IPS_MAGIC: &[u8] = b"PATCH";
+const IPS_EOF: u32 = 0x454F46;
+const UPS_MAGIC: &[u8] = b"UPS1";
+const BPS_MAGIC: &[u8] = b"BPS1";
+
+// Both `UPS` and `BPS` end with the checksums of the source, the target
+// and the patch itself.
+const FOOTER_SIZE: usize = 12;
+
+// Nothing larger can be loaded anyway, a corrupted or malicious patch
+// must not make us allocate more than that.
+const MAX_TARGET_LEN: usize = MAX_ROM_BANKS * ROM_BANK_SIZE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchFormat
+{
+    Ips,
+    Ups,
+    Bps,
+}
+
+// Patch distributed with translations and rom hacks. The format is
+// detected from the magic bytes at the beginning of the file.
+#[derive(Debug, Clone)]
+pub struct Patch
+{
+    format: PatchFormat,
+    data: Vec<u8>,
+}
+
+impl Patch
+{
+    pub fn new(data: Vec<u8>) -> Result<Self, CartridgeError>
+    {
+        let format = match data {
+            _ if data.starts_with(IPS_MAGIC) => PatchFormat::Ips,
+            _ if data.starts_with(UPS_MAGIC) => PatchFormat::Ups,
+            _ if data.starts_with(BPS_MAGIC) => PatchFormat::Bps,
+            _ => return Err(CartridgeError::Patch("unknown patch format")),
+        };
+        Ok(Self { format, data })
+    }
+
+    pub fn from_file<P>(path: P) -> Result<Self, CartridgeError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::new(fs::read(path)?)
+    }
+
+    #[inline]
+    pub fn format(&self) -> PatchFormat
+    {
+        self.format
+    }
+
+    pub fn apply(&self, rom: &[u8]) -> Result<Vec<u8>, CartridgeError>
+    {
+        match self.format {
+            PatchFormat::Ips => apply_ips(&self.data, rom),
+            PatchFormat::Ups => apply_ups(&self.data, rom),
+            PatchFormat::Bps => apply_bps(&self.data, rom),
+        }
+    }
+}
+
+struct PatchReader<'a>
+{
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PatchReader<'a>
+{
+    fn new(data: &'a [u8], pos: usize) -> Self
+    {
+        Self { data, pos }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], CartridgeError>
+    {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.pos..end))
+            .ok_or(CartridgeError::Patch("unexpected end of patch"))?;
+        self.pos += len;
+
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, CartridgeError>
+    {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16_be(&mut self) -> Result<usize, CartridgeError>
+    {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+    }
+
+    fn u24_be(&mut self) -> Result<u32, CartridgeError>
+    {
+        let bytes = self.bytes(3)?;
+        Ok(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+
+    fn u32_le(&mut self) -> Result<u32, CartridgeError>
+    {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    // Variable length number shared by `UPS` and `BPS`. Every byte holds
+    // 7 bits and the highest bit marks the last byte. The encoding has
+    // no redundancy, so the value is incremented after each byte.
+    fn varint(&mut self) -> Result<usize, CartridgeError>
+    {
+        const OUT_OF_RANGE: CartridgeError = CartridgeError::Patch("number out of range");
+
+        let mut value: usize = 0;
+        let mut shift: usize = 1;
+
+        loop {
+            let byte = self.byte()?;
+
+            value = ((byte & 0x7F) as usize)
+                .checked_mul(shift)
+                .and_then(|part| value.checked_add(part))
+                .ok_or(OUT_OF_RANGE)?;
+
+            if byte & 0x80 != 0 {
+                return Ok(value);
+            }
+            // Multiplying instead of shifting, `checked_shl` does not
+            // catch the bits shifted out.
+            shift = shift.checked_mul(0x80).ok_or(OUT_OF_RANGE)?;
+            value = value.checked_add(shift).ok_or(OUT_OF_RANGE)?;
+        }
+    }
+
+    fn is_at(&self, pos: usize) -> bool
+    {
+        self.pos >= pos
+    }
+}
+
+// Both `UPS` and `BPS` store the checksums of the source, the target and
+// the patch in the footer. Returns the first two after making sure that
+// the patch itself is not corrupted.
+fn read_footer(patch: &[u8]) -> Result<(u32, u32), CartridgeError>
+{
+    if patch.len() < BPS_MAGIC.len() + FOOTER_SIZE {
+        return Err(CartridgeError::Patch("unexpected end of patch"));
+    }
+    let mut footer = PatchReader::new(patch, patch.len() - FOOTER_SIZE);
+
+    let source = footer.u32_le()?;
+    let target = footer.u32_le()?;
+    let expected = footer.u32_le()?;
+    let computed = crc32(&patch[..patch.len() - 4]);
+
+    if expected != computed {
+        return Err(CartridgeError::PatchCorrupted { expected, computed });
+    }
+    Ok((source, target))
+}
+
+fn verify_target_len(len: usize) -> Result<(), CartridgeError>
+{
+    match len > MAX_TARGET_LEN {
+        true => Err(CartridgeError::RomTooLarge(len)),
+        false => Ok(()),
+    }
+}
+
+fn verify_source(expected: u32, rom: &[u8]) -> Result<(), CartridgeError>
+{
+    let computed = crc32(rom);
+
+    match expected == computed {
+        true => Ok(()),
+        false => Err(CartridgeError::PatchSourceMismatch { expected, computed }),
+    }
+}
+
+fn verify_target(expected: u32, rom: &[u8]) -> Result<(), CartridgeError>
+{
+    let computed = crc32(rom);
+
+    match expected == computed {
+        true => Ok(()),
+        false => Err(CartridgeError::PatchTargetMismatch { expected, computed }),
+    }
+}
+
+// The oldest and simplest format, a list of records which overwrite
+// the rom at the given offsets. It has no checksums, so there is no
+// way to tell whether the patch was made for this rom.
+fn apply_ips(patch: &[u8], rom: &[u8]) -> Result<Vec<u8>, CartridgeError>
+{
+    let mut target = rom.to_vec();
+    let mut reader = PatchReader::new(patch, IPS_MAGIC.len());
+
+    loop {
+        let offset = reader.u24_be()?;
+
+        if offset == IPS_EOF {
+            break;
+        }
+        let offset = offset as usize;
+        let len = reader.u16_be()?;
+
+        // Records without the length are run-length encoded.
+        let (len, fill) = match len {
+            0 => (reader.u16_be()?, Some(reader.byte()?)),
+            len => (len, None),
+        };
+        if target.len() < offset + len {
+            target.resize(offset + len, 0x00);
+        }
+        match fill {
+            Some(fill) => target[offset..offset + len].fill(fill),
+            None => target[offset..offset + len].copy_from_slice(reader.bytes(len)?),
+        }
+    }
+    // Some patches shrink the rom, the new size follows the end marker.
+    if let Ok(len) = reader.u24_be() {
+        target.truncate(len as usize);
+    }
+    Ok(target)
+}
+
+// Every hunk is a distance from the previous one, followed by a run of
+// bytes xor-ed with the source, terminated with zero.
+fn apply_ups(patch: &[u8], rom: &[u8]) -> Result<Vec<u8>, CartridgeError>
+{
+    let (source_crc, target_crc) = read_footer(patch)?;
+    let end = patch.len() - FOOTER_SIZE;
+
+    let mut reader = PatchReader::new(&patch[..end], UPS_MAGIC.len());
+    let source_len = reader.varint()?;
+    let target_len = reader.varint()?;
+
+    if source_len != rom.len() {
+        return Err(CartridgeError::PatchSourceMismatch {
+            expected: source_crc,
+            computed: crc32(rom),
+        });
+    }
+    verify_source(source_crc, rom)?;
+    verify_target_len(target_len)?;
+
+    let mut target = rom.to_vec();
+    target.resize(target_len, 0x00);
+
+    let mut pos: usize = 0;
+
+    while !reader.is_at(end) {
+        // The hunk can end right after the target, but never start
+        // past it, which also keeps the position from overflowing.
+        pos = pos
+            .checked_add(reader.varint()?)
+            .filter(|pos| *pos <= target.len())
+            .ok_or(CartridgeError::Patch("hunk out of range"))?;
+
+        loop {
+            let byte = reader.byte()?;
+
+            if byte == 0 {
+                pos += 1;
+                break;
+            }
+            let out = target
+                .get_mut(pos)
+                .ok_or(CartridgeError::Patch("hunk out of range"))?;
+            *out ^= byte;
+            pos += 1;
+        }
+    }
+    verify_target(target_crc, &target)?;
+
+    Ok(target)
+}
+
+// Every action either copies the bytes from the source or from the
+// patch, or repeats the bytes which were already written to the target.
+fn apply_bps(patch: &[u8], rom: &[u8]) -> Result<Vec<u8>, CartridgeError>
+{
+    let (source_crc, target_crc) = read_footer(patch)?;
+    let end = patch.len() - FOOTER_SIZE;
+
+    let mut reader = PatchReader::new(&patch[..end], BPS_MAGIC.len());
+    let source_len = reader.varint()?;
+    let target_len = reader.varint()?;
+    let metadata_len = reader.varint()?;
+    reader.bytes(metadata_len)?;
+
+    if source_len != rom.len() {
+        return Err(CartridgeError::PatchSourceMismatch {
+            expected: source_crc,
+            computed: crc32(rom),
+        });
+    }
+    verify_source(source_crc, rom)?;
+    verify_target_len(target_len)?;
+
+    const OUT_OF_RANGE: CartridgeError = CartridgeError::Patch("action out of range");
+
+    let mut target: Vec<u8> = Vec::with_capacity(target_len);
+    let mut source_offset: isize = 0;
+    let mut target_offset: isize = 0;
+
+    while !reader.is_at(end) {
+        let action = reader.varint()?;
+        let len = (action >> 2) + 1;
+
+        if target.len() + len > target_len {
+            return Err(OUT_OF_RANGE);
+        }
+        match action & 0x03 {
+            // Source read, copies the source at the same offset.
+            0 => {
+                let start = target.len();
+                let bytes = rom.get(start..start + len).ok_or(OUT_OF_RANGE)?;
+                target.extend_from_slice(bytes);
+            }
+            // Target read, copies the bytes stored in the patch.
+            1 => target.extend_from_slice(reader.bytes(len)?),
+            // Source copy, copies the source from a relative offset.
+            2 => {
+                source_offset = source_offset
+                    .checked_add(read_relative_offset(&mut reader)?)
+                    .ok_or(OUT_OF_RANGE)?;
+
+                let start = usize::try_from(source_offset).map_err(|_| OUT_OF_RANGE)?;
+                let bytes = rom.get(start..start + len).ok_or(OUT_OF_RANGE)?;
+                target.extend_from_slice(bytes);
+
+                // Both fit in the source, so the sum can not overflow.
+                source_offset += len as isize;
+            }
+            // Target copy, the ranges can overlap, so the bytes have to
+            // be copied one by one.
+            _ => {
+                target_offset = target_offset
+                    .checked_add(read_relative_offset(&mut reader)?)
+                    .ok_or(OUT_OF_RANGE)?;
+
+                let start = usize::try_from(target_offset).map_err(|_| OUT_OF_RANGE)?;
+
+                for idx in start..start + len {
+                    let byte = *target.get(idx).ok_or(OUT_OF_RANGE)?;
+                    target.push(byte);
+                }
+                target_offset += len as isize;
+            }
+        }
+    }
+    if target.len() != target_len {
+        return Err(CartridgeError::Patch("unexpected end of patch"));
+    }
+    verify_target(target_crc, &target)?;
+
+    Ok(target)
+}
+
+// The lowest bit holds the sign of the offset.
+fn read_relative_offset(reader: &mut PatchReader) -> Result<isize, CartridgeError>
+{
+    let value = reader.varint()?;
+    let offset = (value >> 1) as isize;
+
+    match value & 1 {
+        1 => Ok(-offset),
+        _ => Ok(offset),
+    }
+}
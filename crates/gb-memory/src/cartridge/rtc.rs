@@ -0,0 +1,416 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Source of the current time for the cartridges with a real-time
+// clock. The default one uses the system time, but it can be replaced
+// to drive the time manually, e.g. in tests.
+pub trait Clock: Send + Sync
+{
+    // Returns the number of seconds since the UNIX epoch.
+    fn now(&self) -> u64;
+}
+
+// Lets the same clock drive more than one cartridge, e.g. all of the
+// cartridges loaded by a cloned builder.
+impl<C> Clock for Arc<C>
+where
+    C: Clock + ?Sized,
+{
+    fn now(&self) -> u64
+    {
+        (**self).now()
+    }
+}
+
+// Formatting must not read the clock, the host clock may be slow or
+// have side effects.
+impl fmt::Debug for dyn Clock
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str("dyn Clock")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock
+{
+    fn now(&self) -> u64
+    {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+// The footer appended to the save file by `VBA-M`, `BGB` and `SameBoy`.
+// It consists of the current and latched registers, each one stored as
+// a little-endian 32-bit value, followed by the UNIX timestamp of the
+// moment the game was saved. Older versions store the timestamp on 32
+// bits, which makes the footer 4 bytes shorter.
+pub const RTC_FOOTER_LEN: usize = 48;
+pub const RTC_FOOTER_LEGACY_LEN: usize = 44;
+
+// Bits of the upper day counter register.
+pub const DAY_HIGH_BIT: u8 = 0x01;
+pub const HALT_BIT: u8 = 0x40;
+pub const DAY_CARRY_BIT: u8 = 0x80;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RtcRegisters
+{
+    pub seconds: u8,
+    pub minutes: u8,
+    pub hours: u8,
+    pub day_low: u8,
+    // Bit 0 is the 9th bit of the day counter, bit 6 halts the clock
+    // and bit 7 is set when the day counter overflows.
+    pub day_high: u8,
+}
+
+impl RtcRegisters
+{
+    // Registers are selected with the values 0x08 - 0x0C written
+    // to the ram bank register of the mapper.
+    pub fn read(&self, reg: u8) -> u8
+    {
+        match reg {
+            0x08 => self.seconds,
+            0x09 => self.minutes,
+            0x0A => self.hours,
+            0x0B => self.day_low,
+            0x0C => self.day_high,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write(&mut self, reg: u8, val: u8)
+    {
+        match reg {
+            0x08 => self.seconds = val & 0x3F,
+            0x09 => self.minutes = val & 0x3F,
+            0x0A => self.hours = val & 0x1F,
+            0x0B => self.day_low = val,
+            0x0C => self.day_high = val & (DAY_HIGH_BIT | HALT_BIT | DAY_CARRY_BIT),
+            _ => {}
+        }
+    }
+
+    #[inline]
+    pub fn is_halted(&self) -> bool
+    {
+        self.day_high & HALT_BIT != 0
+    }
+
+    #[inline]
+    pub fn days(&self) -> u16
+    {
+        (((self.day_high & DAY_HIGH_BIT) as u16) << 8) | self.day_low as u16
+    }
+
+    fn to_footer(self) -> [u32; 5]
+    {
+        [
+            self.seconds as u32,
+            self.minutes as u32,
+            self.hours as u32,
+            self.day_low as u32,
+            self.day_high as u32,
+        ]
+    }
+
+    fn from_footer(data: &[u8]) -> Self
+    {
+        let reg = |idx: usize| {
+            let bytes = data[idx * 4..idx * 4 + 4].try_into().unwrap();
+            u32::from_le_bytes(bytes) as u8
+        };
+        let mut registers = Self::default();
+
+        for (idx, reg_idx) in (0x08..=0x0C).enumerate() {
+            registers.write(reg_idx, reg(idx));
+        }
+        registers
+    }
+
+    pub fn advance(&mut self, seconds: u64)
+    {
+        let seconds = self.seconds as u64 + seconds;
+        self.seconds = (seconds % 60) as u8;
+
+        let minutes = self.minutes as u64 + seconds / 60;
+        self.minutes = (minutes % 60) as u8;
+
+        let hours = self.hours as u64 + minutes / 60;
+        self.hours = (hours % 24) as u8;
+
+        // The day counter is 9 bits wide, after reaching 511 it wraps
+        // around and sets the carry bit, which stays set until it is
+        // cleared by the game.
+        let days = self.days() as u64 + hours / 24;
+
+        if days > 0x1FF {
+            self.day_high |= DAY_CARRY_BIT;
+        }
+        let days = days & 0x1FF;
+
+        self.day_low = (days & 0xFF) as u8;
+        self.day_high = (self.day_high & !DAY_HIGH_BIT) | (days >> 8) as u8;
+    }
+}
+
+// Real-time clock used by the `MBC3` cartridges. The registers are
+// not ticking on their own, instead every access catches up with the
+// time which passed since the last synchronization.
+#[derive(Debug)]
+pub struct Rtc
+{
+    clock: Box<dyn Clock>,
+    registers: RtcRegisters,
+    // Copy of the registers made during latching, this is what the
+    // game actually reads.
+    latched: RtcRegisters,
+    // Last value written to the latch register. Latching happens when
+    // 0x00 is followed by 0x01.
+    latch: u8,
+    last_sync: u64,
+}
+
+impl Rtc
+{
+    pub fn new(clock: Box<dyn Clock>) -> Self
+    {
+        let last_sync = clock.now();
+
+        Self {
+            clock,
+            registers: RtcRegisters::default(),
+            latched: RtcRegisters::default(),
+            latch: 0xFF,
+            last_sync,
+        }
+    }
+
+    #[inline]
+    pub fn read(&self, reg: u8) -> u8
+    {
+        self.latched.read(reg)
+    }
+
+    pub fn write(&mut self, reg: u8, val: u8)
+    {
+        self.sync();
+        self.registers.write(reg, val);
+    }
+
+    pub fn write_latch(&mut self, val: u8)
+    {
+        if self.latch == 0x00 && val == 0x01 {
+            self.latch();
+        }
+        self.latch = val;
+    }
+
+    // Copies the current time to the registers visible to the game.
+    pub fn latch(&mut self)
+    {
+        self.sync();
+        self.latched = self.registers;
+    }
+
+    pub fn dump_footer(&mut self) -> Vec<u8>
+    {
+        self.sync();
+
+        let registers = self.registers.to_footer();
+        let latched = self.latched.to_footer();
+
+        let mut footer: Vec<u8> = registers
+            .iter()
+            .chain(latched.iter())
+            .flat_map(|reg| reg.to_le_bytes())
+            .collect();
+        footer.extend_from_slice(&self.last_sync.to_le_bytes());
+
+        footer
+    }
+
+    // Restores the state saved by `dump_footer` or by other emulators.
+    // The clock catches up with the time which passed since the save
+    // on the next access. Returns false if the footer is not valid.
+    pub fn load_footer(&mut self, data: &[u8]) -> bool
+    {
+        let timestamp = match data.len() {
+            RTC_FOOTER_LEN => u64::from_le_bytes(data[40..48].try_into().unwrap()),
+            RTC_FOOTER_LEGACY_LEN => u32::from_le_bytes(data[40..44].try_into().unwrap()) as u64,
+            _ => return false,
+        };
+        self.registers = RtcRegisters::from_footer(&data[..20]);
+        self.latched = RtcRegisters::from_footer(&data[20..40]);
+        self.last_sync = timestamp;
+
+        true
+    }
+
+    fn sync(&mut self)
+    {
+        let now = self.clock.now();
+
+        // The clock does not count while halted, so the time which
+        // passed is simply dropped.
+        if !self.registers.is_halted() {
+            let elapsed = now.saturating_sub(self.last_sync);
+            self.registers.advance(elapsed);
+        }
+        self.last_sync = now;
+    }
+}
+
+// The footer used by `SameBoy` for the HuC3 clock. It holds the UNIX
+// timestamp, the minutes and the days, followed by the alarm settings.
+pub const HUC3_FOOTER_LEN: usize = 17;
+
+const MINUTES_PER_DAY: u64 = 1440;
+const HUC3_DAYS_MASK: u16 = 0x0FFF;
+const HUC3_MEMORY_SIZE: usize = 0x100;
+
+// Real-time clock of the HuC3, which counts only the minutes of the day
+// and the days. The game talks to it with commands and the data is
+// exchanged through the nibble-wide memory of the chip, the current
+// time is copied to and from its first 6 nibbles.
+#[derive(Debug)]
+pub struct HuC3Rtc
+{
+    clock: Box<dyn Clock>,
+    minutes: u16,
+    days: u16,
+    last_sync: u64,
+    memory: [u8; HUC3_MEMORY_SIZE],
+    addr: u8,
+    // Result of the last command, the command in the upper nibble and
+    // the value in the lower one.
+    response: u8,
+}
+
+impl HuC3Rtc
+{
+    pub fn new(clock: Box<dyn Clock>) -> Self
+    {
+        let last_sync = clock.now();
+
+        Self {
+            clock,
+            minutes: 0,
+            days: 0,
+            last_sync,
+            memory: [0x00; HUC3_MEMORY_SIZE],
+            addr: 0x00,
+            response: 0x00,
+        }
+    }
+
+    #[inline]
+    pub fn read(&self) -> u8
+    {
+        self.response
+    }
+
+    // Bits 4 - 6 hold the command and bits 0 - 3 its argument.
+    pub fn command(&mut self, val: u8)
+    {
+        let command = (val >> 4) & 0x07;
+        let arg = val & 0x0F;
+
+        match command {
+            // Read the nibble and move to the next one.
+            0x01 => {
+                self.response = (command << 4) | self.memory[self.addr as usize];
+                self.addr = self.addr.wrapping_add(1);
+            }
+            // Write the nibble and move to the next one.
+            0x03 => {
+                self.memory[self.addr as usize] = arg;
+                self.addr = self.addr.wrapping_add(1);
+            }
+            0x04 => self.addr = (self.addr & 0xF0) | arg,
+            0x05 => self.addr = (self.addr & 0x0F) | (arg << 4),
+            0x06 => self.extended_command(arg),
+            _ => {}
+        }
+    }
+
+    pub fn dump_footer(&mut self) -> Vec<u8>
+    {
+        self.sync();
+
+        let mut footer = Vec::with_capacity(HUC3_FOOTER_LEN);
+        footer.extend_from_slice(&self.last_sync.to_le_bytes());
+        footer.extend_from_slice(&self.minutes.to_le_bytes());
+        footer.extend_from_slice(&self.days.to_le_bytes());
+        // The alarm is not emulated, so it is always stored as disabled.
+        footer.extend_from_slice(&[0x00; 5]);
+
+        footer
+    }
+
+    pub fn load_footer(&mut self, data: &[u8]) -> bool
+    {
+        if data.len() != HUC3_FOOTER_LEN {
+            return false;
+        }
+        self.last_sync = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        self.minutes = u16::from_le_bytes([data[8], data[9]]) % MINUTES_PER_DAY as u16;
+        self.days = u16::from_le_bytes([data[10], data[11]]) & HUC3_DAYS_MASK;
+
+        true
+    }
+
+    fn extended_command(&mut self, arg: u8)
+    {
+        match arg {
+            // Copy the current time to the memory.
+            0x00 => {
+                self.sync();
+
+                for idx in 0..3 {
+                    self.memory[idx] = ((self.minutes >> (idx * 4)) & 0x0F) as u8;
+                    self.memory[idx + 3] = ((self.days >> (idx * 4)) & 0x0F) as u8;
+                }
+            }
+            // Set the time from the memory.
+            0x01 => {
+                self.sync();
+
+                let nibbles = |start: usize| {
+                    (0..3).fold(0, |acc, idx| {
+                        acc | (self.memory[start + idx] as u16) << (idx * 4)
+                    })
+                };
+                self.minutes = nibbles(0) % MINUTES_PER_DAY as u16;
+                self.days = nibbles(3) & HUC3_DAYS_MASK;
+            }
+            // Status check, the games expect the chip to answer with 1.
+            0x02 => self.response = 0x61,
+            _ => {}
+        }
+    }
+
+    // The clock counts whole minutes, so the remaining seconds are
+    // kept for the next sync.
+    fn sync(&mut self)
+    {
+        let elapsed = self.clock.now().saturating_sub(self.last_sync) / 60;
+        let minutes = self.minutes as u64 + elapsed;
+
+        self.minutes = (minutes % MINUTES_PER_DAY) as u16;
+        self.days = (self.days as u64 + minutes / MINUTES_PER_DAY) as u16 & HUC3_DAYS_MASK;
+        self.last_sync += elapsed * 60;
+    }
+}
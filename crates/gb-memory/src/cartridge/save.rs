@@ -0,0 +1,114 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{fs, io};
+
+// Games usually write the whole save in one go, so we wait until the
+// writes stop for a while before flushing the ram to the disk.
+pub const SETTLE_DELAY: Duration = Duration::from_secs(1);
+
+// Keeps track of the `.sav` file of a cartridge with battery backed
+// ram and of the writes which were not persisted yet.
+#[derive(Debug)]
+pub struct SaveFile
+{
+    path: PathBuf,
+    // Set by every write to the ram, the time is taken only once the
+    // write is noticed by `is_settled`, which is polled a lot less often.
+    written: bool,
+    last_write: Option<Instant>,
+}
+
+impl SaveFile
+{
+    pub fn new(path: PathBuf) -> Self
+    {
+        Self {
+            path,
+            written: false,
+            last_write: None,
+        }
+    }
+
+    // By convention the save file sits next to the rom and differs
+    // only by the extension.
+    pub fn next_to(rom_path: &Path) -> Self
+    {
+        Self::new(rom_path.with_extension("sav"))
+    }
+
+    #[inline]
+    pub fn path(&self) -> &Path
+    {
+        &self.path
+    }
+
+    // Returns `None` if the game was never saved before.
+    pub fn read(&self) -> io::Result<Option<Vec<u8>>>
+    {
+        match fs::read(&self.path) {
+            Ok(data) => Ok(Some(data)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    // The data is written to a temporary file next to the save, which
+    // then replaces it, so a crash in the middle never leaves the game
+    // with a half written save.
+    pub fn write(&mut self, data: &[u8]) -> io::Result<()>
+    {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        let result = write_synced(Path::new(&tmp_path), data)
+            .and_then(|_| fs::rename(&tmp_path, &self.path));
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result?;
+
+        self.written = false;
+        self.last_write = None;
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn mark_dirty(&mut self)
+    {
+        self.written = true;
+    }
+
+    #[inline]
+    pub fn is_dirty(&self) -> bool
+    {
+        self.written || self.last_write.is_some()
+    }
+
+    // The ram is settled once nothing was written to it between the
+    // calls made over the last `SETTLE_DELAY`.
+    pub fn is_settled(&mut self) -> bool
+    {
+        if self.written {
+            self.written = false;
+            self.last_write = Some(Instant::now());
+
+            return false;
+        }
+        self.last_write
+            .is_some_and(|last_write| last_write.elapsed() >= SETTLE_DELAY)
+    }
+}
+
+fn write_synced(path: &Path, data: &[u8]) -> io::Result<()>
+{
+    let mut file = File::create(path)?;
+    file.write_all(data)?;
+    file.sync_all()
+}
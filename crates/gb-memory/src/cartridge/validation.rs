@@ -0,0 +1,120 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fmt;
+
+use crate::cartridge::errors::CartridgeWarning;
+
+// The bitmap displayed by the boot rom. The `DMG` boot rom compares
+// it byte by byte with the cartridge and locks up on any difference.
+#[rustfmt::skip]
+pub const NINTENDO_LOGO: [u8; 48] = [
+    0xCE, 0xED, 0x66, 0x66, 0xCC, 0x0D, 0x00, 0x0B, 0x03, 0x73, 0x00, 0x83,
+    0x00, 0x0C, 0x00, 0x0D, 0x00, 0x08, 0x11, 0x1F, 0x88, 0x89, 0x00, 0x0E,
+    0xDC, 0xCC, 0x6E, 0xE6, 0xDD, 0xDD, 0xD9, 0x99, 0xBB, 0xBB, 0x67, 0x63,
+    0x6E, 0x0E, 0xEC, 0xCC, 0xDD, 0xDC, 0x99, 0x9F, 0xBB, 0xB9, 0x33, 0x3E,
+];
+
+// Strict validation rejects every cartridge which would not pass the
+// checks done by the `DMG` boot rom (logo and header checksum). The
+// lenient one only reports the problems as warnings, which is useful
+// for homebrew roms that often do not bother with a correct header.
+// Lenient is the default, so the roms which loaded before the header
+// was validated keep loading.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Validation
+{
+    Strict,
+    #[default]
+    Lenient,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Checksum<T>
+{
+    pub expected: T,
+    pub computed: T,
+}
+
+impl<T: PartialEq> Checksum<T>
+{
+    #[inline]
+    pub fn is_valid(&self) -> bool
+    {
+        self.expected == self.computed
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ValidationReport
+{
+    pub logo_valid: bool,
+    pub header_checksum: Checksum<u8>,
+    pub global_checksum: Checksum<u16>,
+}
+
+impl ValidationReport
+{
+    // The global checksum is never verified by the hardware, so it
+    // does not have any influence on whether the game would boot.
+    #[inline]
+    pub fn passes_boot_check(&self) -> bool
+    {
+        self.logo_valid && self.header_checksum.is_valid()
+    }
+
+    #[inline]
+    pub fn is_valid(&self) -> bool
+    {
+        self.passes_boot_check() && self.global_checksum.is_valid()
+    }
+
+    pub fn warnings(&self) -> Vec<CartridgeWarning>
+    {
+        let mut warnings = Vec::new();
+
+        if !self.logo_valid {
+            warnings.push(CartridgeWarning::InvalidLogo);
+        }
+        if !self.header_checksum.is_valid() {
+            warnings.push(CartridgeWarning::HeaderChecksum {
+                expected: self.header_checksum.expected,
+                computed: self.header_checksum.computed,
+            });
+        }
+        if !self.global_checksum.is_valid() {
+            warnings.push(CartridgeWarning::GlobalChecksum {
+                expected: self.global_checksum.expected,
+                computed: self.global_checksum.computed,
+            });
+        }
+        warnings
+    }
+}
+
+impl fmt::Display for ValidationReport
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let status = |valid: bool| match valid {
+            true => "ok",
+            false => "MISMATCH",
+        };
+
+        writeln!(f, "Nintendo logo:     {}", status(self.logo_valid))?;
+        writeln!(
+            f,
+            "Header checksum:   {} (expected 0x{:02X}, computed 0x{:02X})",
+            status(self.header_checksum.is_valid()),
+            self.header_checksum.expected,
+            self.header_checksum.computed
+        )?;
+        write!(
+            f,
+            "Global checksum:   {} (expected 0x{:04X}, computed 0x{:04X})",
+            status(self.global_checksum.is_valid()),
+            self.global_checksum.expected,
+            self.global_checksum.computed
+        )
+    }
+}
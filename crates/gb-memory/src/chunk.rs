@@ -3,34 +3,39 @@
 
 use crate::MemoryAccess;
 
-#[derive(Debug, Default)]
-pub struct MemoryChunk<const START_ADDR: u16, const END_ADDR: u16>
+#[derive(Debug)]
+pub struct MemoryChunk<const START_ADDR: u16, const SIZE: usize>
 {
-    data: Vec<u8>,
+    data: [u8; SIZE],
 }
 
-impl<const START_ADDR: u16, const END_ADDR: u16> MemoryChunk<START_ADDR, END_ADDR>
+impl<const START_ADDR: u16, const SIZE: usize> MemoryChunk<START_ADDR, SIZE>
 {
-    pub const SIZE: usize = (END_ADDR - START_ADDR) as usize;
-
-    pub fn new() -> Self
+    pub const fn new() -> Self
     {
-        let data = vec![0x0; Self::SIZE];
-        Self { data }
+        Self { data: [0x0; SIZE] }
     }
 
     pub fn from_slice(data: &[u8]) -> Self
     {
-        Self::from_vec(data.to_vec())
+        assert!(data.len() <= SIZE);
+
+        let mut chunk = Self::new();
+        chunk.data[..data.len()].copy_from_slice(data);
+
+        chunk
     }
 
-    pub fn from_vec(mut data: Vec<u8>) -> Self
+    #[inline]
+    pub fn as_slice(&self) -> &[u8]
     {
-        assert!(data.len() <= Self::SIZE);
-
-        data.resize_with(Self::SIZE, Default::default);
+        &self.data
+    }
 
-        Self { data }
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8]
+    {
+        &mut self.data
     }
 
     #[inline]
@@ -40,7 +45,15 @@ impl<const START_ADDR: u16, const END_ADDR: u16> MemoryChunk<START_ADDR, END_ADD
     }
 }
 
-impl<const START_ADDR: u16, const END_ADDR: u16> MemoryAccess for MemoryChunk<START_ADDR, END_ADDR>
+impl<const START_ADDR: u16, const SIZE: usize> Default for MemoryChunk<START_ADDR, SIZE>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<const START_ADDR: u16, const SIZE: usize> MemoryAccess for MemoryChunk<START_ADDR, SIZE>
 {
     fn read_byte(&self, addr: u16) -> u8
     {
@@ -4,11 +4,21 @@
 pub mod rom
 {
     pub const HEADER_SIZE: usize = 0x150;
+    pub const LOGO_START: usize = 0x104;
+    pub const LOGO_END: usize = 0x134;
     pub const TITLE_START: usize = 0x134;
     pub const TITLE_END: usize = 0x144;
+    pub const MANUFACTURER_CODE_START: usize = 0x13F;
+    pub const MANUFACTURER_CODE_END: usize = 0x143;
     pub const CGB_FLAG: usize = 0x143;
+    pub const NEW_LICENSEE_CODE: usize = 0x144;
+    pub const SGB_FLAG: usize = 0x146;
     pub const CARTRIDGE_TYPE: usize = 0x147;
     pub const ROM_SIZE: usize = 0x148;
     pub const RAM_SIZE: usize = 0x149;
+    pub const DESTINATION_CODE: usize = 0x14A;
+    pub const OLD_LICENSEE_CODE: usize = 0x14B;
+    pub const ROM_VERSION: usize = 0x14C;
     pub const CHECKSUM: usize = 0x14D;
+    pub const GLOBAL_CHECKSUM: usize = 0x14E;
 }
@@ -4,12 +4,24 @@
 mod bus;
 mod cartridge;
 mod chunk;
-mod consts;
+pub mod consts;
 mod mmu;
+mod page_table;
 
-pub use bus::MemoryBus;
-pub use cartridge::builder::Cartridge;
-pub use cartridge::header::Header;
+pub use bus::{CartridgeMut, MemoryBus};
+pub use cartridge::builder::{Cartridge, CartridgeBuilder};
+pub use cartridge::camera::CameraImage;
+pub use cartridge::database::{DatEntry, RomDatabase};
+pub use cartridge::errors::{CartridgeError, CartridgeWarning};
+pub use cartridge::hash::RomHashes;
+pub use cartridge::header::{CartridgeType, CgbFlag, Destination, Header, Mapper, SgbFlag};
+pub use cartridge::infrared::{InfraredChannel, InfraredLink};
+pub use cartridge::licensee::Licensee;
+pub use cartridge::mbc::banks::{MAX_ROM_BANKS, ROM_BANK_SIZE};
+pub use cartridge::options::CartridgeOptions;
+pub use cartridge::patch::{Patch, PatchFormat};
+pub use cartridge::rtc::{Clock, SystemClock};
+pub use cartridge::validation::{Checksum, NINTENDO_LOGO, Validation, ValidationReport};
 pub use mmu::MMU;
 
 pub trait MemoryAccess
@@ -32,27 +32,53 @@ impl<'a> MMU<'a>
         self.write_byte(addr, a);
         self.write_byte(addr + 1, b);
     }
-}
 
-impl<'a> MemoryAccess for MMU<'a>
-{
-    fn read_byte(&self, addr: u16) -> u8
+    // Regions which are not backed by the page table, either because
+    // accessing them has side effects or they share a page with such
+    // a region.
+    #[cold]
+    fn slow_read_byte(&self, addr: u16) -> u8
     {
         match addr {
             0x0000..=0x7FFF | 0xA000..=0xBFFF => self.bus.cartridge.read_byte(addr),
-            0x8000..=0x9FFF => self.bus.vram.read_byte(addr),
-            0xC000..=0xDFFF => self.bus.wram.read_byte(addr),
+            0xFF80..=0xFFFE => self.bus.hram.read_byte(addr),
             _ => unimplemented!(),
         }
     }
 
-    fn write_byte(&mut self, addr: u16, val: u8)
+    #[cold]
+    fn slow_write_byte(&mut self, addr: u16, val: u8)
     {
         match addr {
-            0x0000..=0x7FFF | 0xA000..=0xBFFF => self.bus.cartridge.write_byte(addr, val),
-            0x8000..=0x9FFF => self.bus.vram.write_byte(addr, val),
-            0xC000..=0xDFFF => self.bus.wram.write_byte(addr, val),
+            0x0000..=0x7FFF | 0xA000..=0xBFFF => {
+                self.bus.cartridge.write_byte(addr, val);
+
+                if self.bus.cartridge.is_bank_write(addr) {
+                    self.bus.remap_cartridge();
+                }
+            }
+            0xFF80..=0xFFFE => self.bus.hram.write_byte(addr, val),
             _ => unimplemented!(),
         }
     }
 }
+
+impl<'a> MemoryAccess for MMU<'a>
+{
+    #[inline]
+    fn read_byte(&self, addr: u16) -> u8
+    {
+        match self.bus.pages.read(addr) {
+            Some(val) => val,
+            None => self.slow_read_byte(addr),
+        }
+    }
+
+    #[inline]
+    fn write_byte(&mut self, addr: u16, val: u8)
+    {
+        if !self.bus.pages.write(addr, val) {
+            self.slow_write_byte(addr, val);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::ptr;
+
+pub const PAGE_SIZE: usize = 0x100;
+const PAGE_COUNT: usize = 0x100;
+
+// The whole 16-bit address space is split into 256 pages, 256 bytes
+// each. A page pointing to a plain memory region (rom banks, work ram)
+// can be accessed directly without going through the `match` in the
+// `MMU`. A null pointer means that the page has to be handled by
+// the slow path, because accessing it has side effects (io, mbc
+// registers) or the region is not mapped at all.
+//
+// The table does not own the memory it points to. The owner has to
+// make sure that the memory outlives the mapping and does not move,
+// which in practice means it must be heap allocated and remapped or
+// unmapped before being dropped or reallocated. Writable memory must
+// also be accessed only through raw pointers derived from the same
+// allocation, a `Box` or a `&mut` reference used after the mapping
+// was created would invalidate the pointers kept in the table.
+pub struct PageTable
+{
+    read: [*const u8; PAGE_COUNT],
+    write: [*mut u8; PAGE_COUNT],
+}
+
+impl PageTable
+{
+    pub const fn new() -> Self
+    {
+        Self {
+            read: [ptr::null(); PAGE_COUNT],
+            write: [ptr::null_mut(); PAGE_COUNT],
+        }
+    }
+
+    pub fn map_read_only(&mut self, addr: u16, data: &[u8])
+    {
+        debug_assert!(data.len().is_multiple_of(PAGE_SIZE));
+
+        let first = page_of(addr);
+
+        for (idx, page) in data.chunks_exact(PAGE_SIZE).enumerate() {
+            self.read[first + idx] = page.as_ptr();
+            self.write[first + idx] = ptr::null_mut();
+        }
+    }
+
+    // SAFETY: The caller must guarantee that `data` points to `len`
+    // bytes which stay valid and are not accessed through references
+    // for as long as the mapping exists.
+    pub unsafe fn map_read_write(&mut self, addr: u16, data: *mut u8, len: usize)
+    {
+        debug_assert!(len.is_multiple_of(PAGE_SIZE));
+
+        let first = page_of(addr);
+
+        for idx in 0..len / PAGE_SIZE {
+            // SAFETY: The offset stays within the region guaranteed by
+            // the caller.
+            let page = unsafe { data.add(idx * PAGE_SIZE) };
+
+            self.read[first + idx] = page;
+            self.write[first + idx] = page;
+        }
+    }
+
+    pub fn unmap(&mut self, addr: u16, len: usize)
+    {
+        let first = page_of(addr);
+
+        for idx in first..first + len / PAGE_SIZE {
+            self.read[idx] = ptr::null();
+            self.write[idx] = ptr::null_mut();
+        }
+    }
+
+    #[inline]
+    pub fn read(&self, addr: u16) -> Option<u8>
+    {
+        let page = self.read[page_of(addr)];
+
+        if page.is_null() {
+            return None;
+        }
+        // SAFETY: Every non-null entry points to the beginning of a
+        // `PAGE_SIZE` long region which is kept alive by the owner of
+        // the table, and the offset is always smaller than a page. Read
+        // only pages come from shared slices of the cartridge rom, which
+        // is never written and is remapped after every bank switch.
+        Some(unsafe { *page.add(offset_of(addr)) })
+    }
+
+    #[inline]
+    pub fn write(&mut self, addr: u16, val: u8) -> bool
+    {
+        let page = self.write[page_of(addr)];
+
+        if page.is_null() {
+            return false;
+        }
+        // SAFETY: Same as in `read`, writable pages are created only by
+        // `map_read_write`, whose caller guarantees exclusive access.
+        unsafe { *page.add(offset_of(addr)) = val };
+
+        true
+    }
+}
+
+// SAFETY: The table is only a cache of pointers into memory owned by
+// the same `MemoryBus`, which moves to the other thread together with
+// it. The pointers are never shared outside of the bus.
+unsafe impl Send for PageTable {}
+
+impl Default for PageTable
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+#[inline]
+fn page_of(addr: u16) -> usize
+{
+    (addr >> 8) as usize
+}
+
+#[inline]
+fn offset_of(addr: u16) -> usize
+{
+    (addr & 0xFF) as usize
+}
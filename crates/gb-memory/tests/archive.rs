@@ -0,0 +1,173 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, CartridgeError, RomHashes, Validation};
+use miniz_oxide::deflate::compress_to_vec;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATE: u16 = 8;
+
+fn rom() -> Vec<u8>
+{
+    (0..0x8000).map(|idx| (idx / 0x100) as u8).collect()
+}
+
+fn crc32(data: &[u8]) -> u32
+{
+    RomHashes::new(data).crc32
+}
+
+// Zip archive with the given entries, made of the local headers followed
+// by the central directory.
+fn zip(entries: &[(&str, u16)], data: &[u8]) -> Vec<u8>
+{
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+
+    for (name, method) in entries {
+        let packed = match *method {
+            METHOD_DEFLATE => compress_to_vec(data, 6),
+            _ => data.to_vec(),
+        };
+        let offset = archive.len() as u32;
+        let fields = [
+            &method.to_le_bytes()[..],
+            &[0x00; 4],
+            &crc32(data).to_le_bytes(),
+            &(packed.len() as u32).to_le_bytes(),
+            &(data.len() as u32).to_le_bytes(),
+            &(name.len() as u16).to_le_bytes(),
+            &[0x00; 2],
+        ]
+        .concat();
+
+        archive.extend_from_slice(b"PK\x03\x04\x14\x00\x00\x00");
+        archive.extend_from_slice(&fields);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(&packed);
+
+        directory.extend_from_slice(b"PK\x01\x02\x14\x00\x14\x00\x00\x00");
+        directory.extend_from_slice(&fields);
+        directory.extend_from_slice(&[0x00; 10]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+    let count = (entries.len() as u16).to_le_bytes();
+    let end = [
+        &b"PK\x05\x06"[..],
+        &[0x00; 4],
+        &count,
+        &count,
+        &(directory.len() as u32).to_le_bytes(),
+        &(archive.len() as u32).to_le_bytes(),
+        &[0x00; 2],
+    ]
+    .concat();
+
+    archive.extend(directory);
+    archive.extend(end);
+    archive
+}
+
+// Gzip file with every optional field of the header present.
+fn gzip(data: &[u8]) -> Vec<u8>
+{
+    // FHCRC, FEXTRA, FNAME and FCOMMENT.
+    let mut archive = vec![0x1F, 0x8B, 0x08, 0x1E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03];
+    archive.extend_from_slice(&[0x04, 0x00, b'G', b'B', 0x00, 0x00]);
+    archive.extend_from_slice(b"game.gb\0");
+    archive.extend_from_slice(b"a comment\0");
+
+    let header_crc = crc32(&archive) as u16;
+    archive.extend_from_slice(&header_crc.to_le_bytes());
+
+    archive.extend(compress_to_vec(data, 6));
+    archive.extend_from_slice(&crc32(data).to_le_bytes());
+    archive.extend_from_slice(&(data.len() as u32).to_le_bytes());
+
+    archive
+}
+
+fn unpack(archive: Vec<u8>) -> Result<Cartridge, CartridgeError>
+{
+    Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(archive)
+}
+
+#[test]
+fn unpacks_stored_and_deflated_zip_entries()
+{
+    for method in [METHOD_STORED, METHOD_DEFLATE] {
+        let cartridge = unpack(zip(&[("game.gb", method)], &rom())).unwrap();
+        assert_eq!(*cartridge.hashes(), RomHashes::new(&rom()));
+    }
+}
+
+#[test]
+fn picks_the_first_rom_or_the_given_entry()
+{
+    let archive = zip(
+        &[
+            ("readme.txt", METHOD_STORED),
+            ("dir/game.gbc", METHOD_DEFLATE),
+        ],
+        &rom(),
+    );
+    let cartridge = unpack(archive.clone()).unwrap();
+    assert_eq!(cartridge.hashes().crc32, crc32(&rom()));
+
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .archive_entry("game.gbc")
+        .from_bytes(archive.clone())
+        .unwrap();
+    assert_eq!(cartridge.hashes().crc32, crc32(&rom()));
+
+    let missing = Cartridge::builder()
+        .archive_entry("other.gb")
+        .from_bytes(archive);
+    assert!(matches!(
+        missing,
+        Err(CartridgeError::ArchiveEntryNotFound(name)) if name == "other.gb"
+    ));
+}
+
+#[test]
+fn zip_without_a_rom_is_rejected()
+{
+    let archive = zip(&[("readme.txt", METHOD_STORED)], b"Not a rom.");
+    assert!(matches!(unpack(archive), Err(CartridgeError::Archive(_))));
+}
+
+#[test]
+fn unpacks_gzip_with_every_header_field()
+{
+    let cartridge = unpack(gzip(&rom())).unwrap();
+    assert_eq!(*cartridge.hashes(), RomHashes::new(&rom()));
+}
+
+#[test]
+fn truncated_archive_is_an_error()
+{
+    for archive in [zip(&[("game.gb", METHOD_DEFLATE)], &rom()), gzip(&rom())] {
+        for len in 4..archive.len() {
+            assert!(
+                matches!(
+                    unpack(archive[..len].to_vec()),
+                    Err(CartridgeError::Archive(_))
+                ),
+                "length {len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn corrupted_entry_is_an_error()
+{
+    let mut archive = zip(&[("game.gb", METHOD_STORED)], &rom());
+    archive[0x1000] ^= 0xFF;
+
+    assert!(matches!(unpack(archive), Err(CartridgeError::Archive(_))));
+}
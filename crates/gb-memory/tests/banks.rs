@@ -0,0 +1,65 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, MemoryAccess, Validation};
+
+const BANK: usize = 0x4000;
+
+// Cartridge with 4 banks of rom, every byte holds the number of its
+// bank.
+fn cartridge(cartridge_type: u8, ram_size: u8) -> Cartridge
+{
+    let mut rom: Vec<u8> = (0..4u8).flat_map(|bank| [bank; BANK]).collect();
+    rom[0x147] = cartridge_type;
+    rom[0x148] = 0x01;
+    rom[0x149] = ram_size;
+
+    Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap()
+}
+
+#[test]
+fn rom_bank_wraps_around_the_rom_size()
+{
+    let mut mbc1 = cartridge(0x01, 0x00);
+    mbc1.write_byte(0x2000, 0x1F);
+    assert_eq!(mbc1.read_byte(0x4000), 0x03);
+
+    let mut mbc3 = cartridge(0x11, 0x00);
+    mbc3.write_byte(0x2000, 0x7E);
+    assert_eq!(mbc3.read_byte(0x4000), 0x02);
+
+    let mut mbc5 = cartridge(0x19, 0x00);
+    mbc5.write_byte(0x2000, 0xFF);
+    mbc5.write_byte(0x3000, 0x01);
+    assert_eq!(mbc5.read_byte(0x4000), 0x03);
+}
+
+#[test]
+fn ram_bank_wraps_around_the_ram_size()
+{
+    // A single bank of ram is mirrored in every bank.
+    let mut cartridge = cartridge(0x1A, 0x02);
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0xA000, 0x42);
+    cartridge.write_byte(0x4000, 0x0F);
+    assert_eq!(cartridge.read_byte(0xA000), 0x42);
+
+    cartridge.write_byte(0xA000, 0x24);
+    cartridge.write_byte(0x4000, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0x24);
+}
+
+#[test]
+fn missing_ram_reads_the_open_bus()
+{
+    let mut cartridge = cartridge(0x1A, 0x00);
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x4000, 0x03);
+
+    cartridge.write_byte(0xA000, 0x42);
+    assert_eq!(cartridge.read_byte(0xA000), 0xFF);
+}
@@ -0,0 +1,88 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fs;
+
+use gb_memory::{Cartridge, Mapper, MemoryAccess, Validation};
+
+const BANK: usize = 0x4000;
+
+// `MBC0` rom with 4 banks, every byte holds the number of its bank.
+fn rom() -> Vec<u8>
+{
+    let mut rom: Vec<u8> = (0..4u8).flat_map(|bank| [bank; BANK]).collect();
+    rom[0x148] = 0x01;
+
+    rom
+}
+
+#[test]
+fn reader_gives_the_same_cartridge_as_bytes()
+{
+    let rom = rom();
+    let from_bytes = Cartridge::from_bytes(rom.clone()).unwrap();
+    let from_reader = Cartridge::from_reader(rom.as_slice()).unwrap();
+
+    assert_eq!(
+        from_reader.header().rom_banks,
+        from_bytes.header().rom_banks
+    );
+    assert_eq!(from_reader.hashes(), from_bytes.hashes());
+    assert_eq!(from_reader.mapper(), Mapper::None);
+}
+
+#[test]
+fn forced_mapper_replaces_the_header_one()
+{
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .mapper(Mapper::Mbc5)
+        .from_bytes(rom())
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc5);
+
+    cartridge.write_byte(0x2000, 0x03);
+    assert_eq!(cartridge.read_byte(0x4000), 0x03);
+}
+
+#[test]
+fn forced_ram_size_replaces_the_header_one()
+{
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .mapper(Mapper::Mbc5)
+        .ram_banks(4)
+        .from_bytes(rom())
+        .unwrap();
+    assert_eq!(cartridge.header().ram_banks, 4);
+
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x4000, 0x03);
+    cartridge.write_byte(0xA000, 0x42);
+    assert_eq!(cartridge.read_byte(0xA000), 0x42);
+}
+
+#[test]
+fn save_goes_to_the_given_path()
+{
+    let path = std::env::temp_dir().join(format!("gb-memory-builder-{}.sav", std::process::id()));
+
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .mapper(Mapper::Mbc5)
+        .ram_banks(1)
+        .battery(true)
+        .save_path(&path)
+        .from_bytes(rom())
+        .unwrap();
+    assert_eq!(cartridge.save_path(), Some(path.as_path()));
+
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0xA000, 0x42);
+    drop(cartridge);
+
+    let data = fs::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(data.len(), 0x2000);
+    assert_eq!(data[0], 0x42);
+}
@@ -0,0 +1,77 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, MemoryAccess, MemoryBus, Validation};
+
+// Mbc1 rom with 4 banks, every byte holds the number of its bank.
+fn mbc1_rom() -> Vec<u8>
+{
+    let mut rom: Vec<u8> = (0..4u8).flat_map(|bank| [bank; 0x4000]).collect();
+    rom[0x147] = 0x01;
+    rom[0x148] = 0x01;
+
+    rom
+}
+
+fn bus() -> MemoryBus
+{
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(mbc1_rom())
+        .unwrap();
+
+    MemoryBus::new(cartridge)
+}
+
+#[test]
+fn reads_the_switched_rom_bank()
+{
+    let mut bus = bus();
+    let mut mmu = bus.mmu();
+
+    assert_eq!(mmu.read_byte(0x0200), 0x00);
+    assert_eq!(mmu.read_byte(0x4000), 0x01);
+
+    mmu.write_byte(0x2000, 0x03);
+    assert_eq!(mmu.read_byte(0x4000), 0x03);
+    assert_eq!(mmu.read_byte(0x7FFF), 0x03);
+}
+
+#[test]
+fn work_and_video_ram_survive_moving_the_bus()
+{
+    let mut bus = bus();
+    bus.mmu().write_byte(0x8000, 0x12);
+    bus.mmu().write_byte(0xDFFF, 0x34);
+
+    // The page table must keep pointing to the same memory.
+    let mut moved = Box::new(bus);
+    let mut mmu = moved.mmu();
+
+    assert_eq!(mmu.read_byte(0x8000), 0x12);
+    assert_eq!(mmu.read_byte(0xDFFF), 0x34);
+
+    mmu.write_word(0xC000, 0xBEEF);
+    assert_eq!(mmu.read_word(0xC000), 0xBEEF);
+}
+
+#[test]
+fn remaps_the_rom_after_cartridge_access()
+{
+    let mut bus = bus();
+
+    bus.cartridge_mut().write_byte(0x2000, 0x02);
+    assert_eq!(bus.mmu().read_byte(0x4000), 0x02);
+    assert_eq!(bus.cartridge().read_byte(0x4000), 0x02);
+}
+
+#[test]
+fn bus_can_be_sent_to_another_thread()
+{
+    let mut bus = bus();
+
+    let value = std::thread::spawn(move || bus.mmu().read_byte(0x4000))
+        .join()
+        .unwrap();
+    assert_eq!(value, 0x01);
+}
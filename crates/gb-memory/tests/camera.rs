@@ -0,0 +1,55 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{CameraImage, CartridgeError};
+
+fn is_rejected(result: Result<CameraImage, CartridgeError>) -> bool
+{
+    matches!(result, Err(CartridgeError::CameraImage(_)))
+}
+
+#[test]
+fn reads_binary_and_plain_pgm()
+{
+    let binary =
+        CameraImage::from_pgm(b"P5\n# comment\n3 2\n255\n\x00\x40\x80\xC0\xFF\x10").unwrap();
+    assert_eq!((binary.width(), binary.height()), (3, 2));
+
+    let plain = CameraImage::from_pgm(b"P2 2 1 15 0 15").unwrap();
+    assert_eq!((plain.width(), plain.height()), (2, 1));
+
+    let wide = CameraImage::from_pgm(b"P5 1 1 65535 \xFF\xFF").unwrap();
+    assert_eq!((wide.width(), wide.height()), (1, 1));
+}
+
+#[test]
+fn rejects_size_not_matching_the_pixels()
+{
+    assert!(is_rejected(CameraImage::new(2, 2, vec![0x00; 3])));
+    assert!(is_rejected(CameraImage::new(0, 2, Vec::new())));
+}
+
+#[test]
+fn rejects_oversized_images()
+{
+    // The product does not fit in `usize`.
+    assert!(is_rejected(CameraImage::new(usize::MAX, 2, Vec::new())));
+    assert!(is_rejected(CameraImage::from_pgm(
+        b"P5 18446744073709551615 2 255 \x00"
+    )));
+    assert!(is_rejected(CameraImage::from_pgm(
+        b"P5 9223372036854775808 2 65535 \x00"
+    )));
+    // Fits, but is far larger than anything a camera produces.
+    assert!(is_rejected(CameraImage::from_pgm(
+        b"P2 100000 100000 255 0"
+    )));
+}
+
+#[test]
+fn rejects_truncated_pgm()
+{
+    assert!(is_rejected(CameraImage::from_pgm(b"P5 4 4 255 \x00\x00")));
+    assert!(is_rejected(CameraImage::from_pgm(b"P2 4 4 255 0 0")));
+    assert!(is_rejected(CameraImage::from_pgm(b"P5 4")));
+}
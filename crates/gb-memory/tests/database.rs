@@ -0,0 +1,157 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{CartridgeError, RomDatabase, RomHashes};
+
+// Database with a single game holding the given rom element.
+fn dat(game: &str, rom: &str) -> String
+{
+    format!(
+        "<?xml version=\"1.0\"?>\n<datafile>\n\t<game \
+         name=\"{game}\">\n\t\t{rom}\n\t</game>\n</datafile>\n"
+    )
+}
+
+#[test]
+fn sha1_known_answers()
+{
+    let vectors = [
+        (&b""[..], "da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+        (b"abc", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1",
+        ),
+    ];
+
+    for (data, sha1) in vectors {
+        assert_eq!(RomHashes::new(data).sha1_hex(), sha1);
+    }
+}
+
+#[test]
+fn crc32_known_answer()
+{
+    assert_eq!(RomHashes::new(b"123456789").crc32, 0xCBF43926);
+}
+
+#[test]
+fn identifies_by_sha1()
+{
+    // The crc32 is wrong on purpose, the sha1 takes precedence.
+    let db = RomDatabase::from_xml(&dat(
+        "Tetris (World) (Rev 1)",
+        "<rom name=\"Tetris.gb\" size=\"3\" crc=\"00000000\" \
+         sha1=\"A9993E364706816ABA3E25717850C26C9CD0D89D\"/>",
+    ))
+    .unwrap();
+
+    let entry = db.identify(&RomHashes::new(b"abc")).unwrap();
+    assert_eq!(entry.name, "Tetris (World) (Rev 1)");
+    assert_eq!(entry.region.as_deref(), Some("World"));
+    assert_eq!(entry.revision.as_deref(), Some("1"));
+
+    assert!(db.identify(&RomHashes::new(b"abd")).is_none());
+}
+
+#[test]
+fn falls_back_to_crc32_and_size()
+{
+    let hashes = RomHashes::new(b"abc");
+    let rom = |size: usize| {
+        dat(
+            "Tetris (World)",
+            &format!(
+                "<rom name=\"Tetris.gb\" size=\"{size}\" crc=\"{:08x}\"/>",
+                hashes.crc32
+            ),
+        )
+    };
+
+    let db = RomDatabase::from_xml(&rom(3)).unwrap();
+    assert_eq!(db.identify(&hashes).unwrap().name, "Tetris (World)");
+
+    // Same crc32 but a different size is another dump.
+    let db = RomDatabase::from_xml(&rom(4)).unwrap();
+    assert!(db.identify(&hashes).is_none());
+
+    let db = RomDatabase::from_xml(&dat(
+        "Tetris (World)",
+        &format!("<rom name=\"Tetris.gb\" crc=\"{:08X}\"/>", hashes.crc32),
+    ))
+    .unwrap();
+    assert!(db.identify(&hashes).is_some());
+}
+
+#[test]
+fn crc32_is_ignored_when_the_entry_has_a_sha1()
+{
+    let hashes = RomHashes::new(b"abc");
+    let db = RomDatabase::from_xml(&dat(
+        "Tetris (World)",
+        &format!(
+            "<rom name=\"Tetris.gb\" size=\"3\" crc=\"{:08x}\" sha1=\"{}\"/>",
+            hashes.crc32,
+            RomHashes::new(b"abd").sha1_hex()
+        ),
+    ))
+    .unwrap();
+
+    assert!(db.identify(&hashes).is_none());
+}
+
+#[test]
+fn attributes_are_unescaped()
+{
+    let db = RomDatabase::from_xml(&dat(
+        "Pok&#233;mon Red &#x26; Blue &amp;#38; &lt;Co&gt; (USA)",
+        "<rom name=\"a.gb\" size=\"3\"/>",
+    ))
+    .unwrap();
+
+    let entry = &db.entries()[0];
+    assert_eq!(entry.name, "Pokémon Red & Blue &#38; <Co> (USA)");
+    assert_eq!(entry.region.as_deref(), Some("USA"));
+
+    // Anything which is not a reference is kept.
+    let db = RomDatabase::from_xml(&dat("A &unknown; &#xZZ; & B", "<rom name=\"a.gb\"/>")).unwrap();
+    assert_eq!(db.entries()[0].name, "A &unknown; &#xZZ; & B");
+}
+
+#[test]
+fn quoted_attributes_can_contain_the_tag_end()
+{
+    let db = RomDatabase::from_xml(&dat(
+        "Left > Right (Japan)",
+        "<rom name='a>b.gb' size=\"3\"/>",
+    ))
+    .unwrap();
+
+    let entry = &db.entries()[0];
+    assert_eq!(entry.name, "Left > Right (Japan)");
+    assert_eq!(entry.size, Some(3));
+}
+
+#[test]
+fn comments_and_errors()
+{
+    let db = RomDatabase::from_xml(&dat(
+        "Tetris (World)",
+        "<!-- <rom name=\"skipped\"> --><rom name=\"a.gb\"/>",
+    ))
+    .unwrap();
+    assert_eq!(db.entries().len(), 1);
+
+    assert!(matches!(
+        RomDatabase::from_xml("<datafile></datafile>"),
+        Err(CartridgeError::Database(_))
+    ));
+    assert!(matches!(
+        RomDatabase::from_xml("<game name=\"unterminated>"),
+        Err(CartridgeError::Database(_))
+    ));
+    assert!(matches!(
+        RomDatabase::from_xml(&dat("Tetris", "<rom name=\"a.gb\" crc=\"xyz\"/>")),
+        Err(CartridgeError::Database(_))
+    ));
+}
@@ -0,0 +1,71 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, CartridgeError, Mapper, MemoryAccess, NINTENDO_LOGO, Validation};
+
+// Nothing but the header, without the Nintendo logo.
+fn short_rom() -> Vec<u8>
+{
+    vec![0x00; 0x150]
+}
+
+#[test]
+fn short_rom_with_a_bad_logo_is_not_sachen()
+{
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(short_rom())
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::None);
+
+    let strict = Cartridge::builder()
+        .validation(Validation::Strict)
+        .from_bytes(short_rom());
+    assert!(matches!(strict, Err(CartridgeError::Rejected(_))));
+}
+
+// Address read from the rom by a locked Sachen mapper, the lines 0, 1,
+// 4 and 6 are swapped and the line 7 is set.
+fn sachen_locked(addr: usize) -> usize
+{
+    let addr = addr | 0x80;
+
+    (addr & !0x53)
+        | (addr & 0x40) >> 6
+        | (addr & 0x10) >> 3
+        | (addr & 0x02) << 3
+        | (addr & 0x01) << 6
+}
+
+// The Nintendo logo is only visible to the boot rom, the game keeps its
+// own one at the usual place.
+fn sachen_rom() -> Vec<u8>
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x104..0x134].fill(0x11);
+
+    for (idx, byte) in NINTENDO_LOGO.iter().enumerate() {
+        rom[sachen_locked(0x104 + idx)] = *byte;
+    }
+    rom
+}
+
+#[test]
+fn sachen_unlocks_at_the_entry_point()
+{
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(sachen_rom())
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::SachenMmc1);
+
+    let logo = |cartridge: &Cartridge| -> Vec<u8> {
+        (0x104..0x134)
+            .map(|addr| cartridge.read_byte(addr))
+            .collect()
+    };
+    assert_eq!(logo(&cartridge), NINTENDO_LOGO);
+
+    cartridge.read_byte(0x0100);
+    assert_eq!(logo(&cartridge), [0x11; 0x30]);
+}
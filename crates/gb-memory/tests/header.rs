@@ -0,0 +1,163 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{CartridgeError, CartridgeType, CgbFlag, Header, Mapper};
+use num_traits::FromPrimitive;
+
+// Header of an `MBC0` cartridge with the given title.
+fn rom(title: &[u8]) -> Vec<u8>
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x134..0x134 + title.len()].copy_from_slice(title);
+
+    rom
+}
+
+#[test]
+fn dmg_only_title_takes_all_16_bytes()
+{
+    // The last character of the title is where the cgb flag would be.
+    let header = Header::new(&rom(b"POKEMON RED GAME")).unwrap();
+
+    assert_eq!(header.cgb, CgbFlag::DmgOnly);
+    assert_eq!(header.title, "POKEMON RED GAME");
+    assert_eq!(header.manufacturer_code, None);
+}
+
+#[test]
+fn short_dmg_only_title_is_trimmed()
+{
+    let header = Header::new(&rom(b"TETRIS")).unwrap();
+
+    assert_eq!(header.cgb, CgbFlag::DmgOnly);
+    assert_eq!(header.title, "TETRIS");
+}
+
+#[test]
+fn cgb_flag_shortens_the_title()
+{
+    let mut rom = rom(b"ZELDA");
+    rom[0x143] = 0x80;
+    let header = Header::new(&rom).unwrap();
+
+    assert_eq!(header.cgb, CgbFlag::SupportsEnhancements);
+    assert_eq!(header.title, "ZELDA");
+
+    rom[0x143] = 0xC0;
+    assert_eq!(Header::new(&rom).unwrap().cgb, CgbFlag::CgbOnly);
+}
+
+#[test]
+fn manufacturer_code_shortens_the_title_further()
+{
+    let mut rom = rom(b"POKEMON_SLVAAXE");
+    rom[0x143] = 0x80;
+    let header = Header::new(&rom).unwrap();
+
+    assert_eq!(header.title, "POKEMON_SLV");
+    assert_eq!(header.manufacturer_code.as_deref(), Some("AAXE"));
+}
+
+#[test]
+fn pgb_values_are_recognized()
+{
+    assert_eq!(CgbFlag::from_u8(0x84), CgbFlag::Pgb);
+    assert_eq!(CgbFlag::from_u8(0x88), CgbFlag::Pgb);
+    assert_eq!(CgbFlag::from_u8(0x7F), CgbFlag::DmgOnly);
+}
+
+#[test]
+fn ram_size_codes_are_counted_in_8_kib_banks()
+{
+    let mut rom = rom(b"TETRIS");
+
+    // 0x01 was never used by any released cartridge.
+    for (code, banks) in [
+        (0x00, 0),
+        (0x01, 0),
+        (0x02, 1),
+        (0x03, 4),
+        (0x04, 16),
+        (0x05, 8),
+    ] {
+        rom[0x149] = code;
+        assert_eq!(
+            Header::new(&rom).unwrap().ram_banks,
+            banks,
+            "code 0x{code:02X}"
+        );
+    }
+}
+
+#[test]
+fn every_documented_cartridge_type_is_known()
+{
+    let documented = [
+        0x00, 0x01, 0x02, 0x03, 0x05, 0x06, 0x08, 0x09, 0x0B, 0x0C, 0x0D, 0x0F, 0x10, 0x11, 0x12,
+        0x13, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x20, 0x22, 0xFC, 0xFD, 0xFE, 0xFF,
+    ];
+
+    for code in 0..=0xFFu8 {
+        let kind = CartridgeType::from_u8(code);
+        assert_eq!(
+            kind.is_some(),
+            documented.contains(&code),
+            "code 0x{code:02X}"
+        );
+        assert!(kind.is_none_or(|kind| kind as u8 == code));
+    }
+}
+
+#[test]
+fn unknown_cartridge_type_is_an_error()
+{
+    let mut rom = rom(b"TETRIS");
+    rom[0x147] = 0x04;
+
+    assert!(matches!(Header::new(&rom), Err(CartridgeError::Header(_))));
+}
+
+#[test]
+fn cartridge_types_map_to_their_mapper()
+{
+    let mappers = [
+        (0x00, Mapper::None),
+        (0x03, Mapper::Mbc1),
+        (0x06, Mapper::Mbc2),
+        (0x0D, Mapper::Mmm01),
+        (0x0F, Mapper::Mbc3),
+        (0x1E, Mapper::Mbc5),
+        (0x20, Mapper::Mbc6),
+        (0x22, Mapper::Mbc7),
+        (0xFC, Mapper::PocketCamera),
+        (0xFD, Mapper::Tama5),
+        (0xFE, Mapper::HuC3),
+        (0xFF, Mapper::HuC1),
+    ];
+
+    for (code, mapper) in mappers {
+        assert_eq!(CartridgeType::from_u8(code).unwrap().mapper(), mapper);
+    }
+}
+
+#[test]
+fn cartridge_type_capabilities()
+{
+    let kind = |code| CartridgeType::from_u8(code).unwrap();
+
+    // The ram of `MBC2` is built into the mapper.
+    assert!(kind(0x05).has_ram() && !kind(0x05).has_battery());
+    assert!(!kind(0x01).has_ram());
+    assert!(kind(0x09).has_ram() && kind(0x09).has_battery());
+
+    // `MBC3+TIMER+BATTERY` keeps the clock running without any ram.
+    assert!(kind(0x0F).has_rtc() && kind(0x0F).has_battery() && !kind(0x0F).has_ram());
+    assert!(!kind(0x13).has_rtc());
+
+    assert!(kind(0x1C).has_rumble() && !kind(0x1C).has_ram());
+    assert!(!kind(0x1B).has_rumble());
+
+    assert!(kind(0x22).has_sensor() && kind(0x22).has_rumble());
+    assert!(kind(0xFC).has_sensor());
+    assert!(!kind(0xFF).has_sensor() && !kind(0xFF).has_rtc());
+}
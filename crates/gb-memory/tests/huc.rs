@@ -0,0 +1,140 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use gb_memory::{Cartridge, Clock, InfraredLink, Mapper, MemoryAccess, Validation};
+
+// Clock moved forward by the test, the copies share the same time.
+#[derive(Clone, Default)]
+struct FakeClock(Arc<AtomicU64>);
+
+impl FakeClock
+{
+    fn advance(&self, seconds: u64)
+    {
+        self.0.fetch_add(seconds, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FakeClock
+{
+    fn now(&self) -> u64
+    {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+fn cartridge(cartridge_type: u8, clock: &FakeClock) -> Cartridge
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = cartridge_type;
+    rom[0x149] = 0x03;
+
+    Cartridge::builder()
+        .validation(Validation::Lenient)
+        .clock(Box::new(clock.clone()))
+        .from_bytes(rom)
+        .unwrap()
+}
+
+#[test]
+fn infrared_reaches_the_other_cartridge()
+{
+    let clock = FakeClock::default();
+    let mut huc1 = cartridge(0xFF, &clock);
+    let mut huc3 = cartridge(0xFE, &clock);
+    assert_eq!(huc1.mapper(), Mapper::HuC1);
+    assert_eq!(huc3.mapper(), Mapper::HuC3);
+
+    let (near, far) = InfraredLink::pair();
+    huc1.connect_infrared(Box::new(near));
+    huc3.connect_infrared(Box::new(far));
+
+    huc1.write_byte(0x0000, 0x0E);
+    huc3.write_byte(0x0000, 0x0E);
+    assert_eq!(huc3.read_byte(0xA000), 0xC0);
+
+    huc1.write_byte(0xA000, 0x01);
+    assert_eq!(huc3.read_byte(0xA000), 0xC1);
+    assert_eq!(huc1.read_byte(0xA000), 0xC0);
+
+    huc3.write_byte(0xA000, 0x01);
+    huc1.write_byte(0xA000, 0x00);
+    assert_eq!(huc3.read_byte(0xA000), 0xC0);
+    assert_eq!(huc1.read_byte(0xA000), 0xC1);
+}
+
+#[test]
+fn huc1_infrared_mode_hides_the_ram()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0xFF, &clock);
+
+    cartridge.write_byte(0x0000, 0x00);
+    cartridge.write_byte(0xA000, 0x42);
+
+    // Without a channel the sensor never sees the light.
+    cartridge.write_byte(0x0000, 0x0E);
+    assert_eq!(cartridge.read_byte(0xA000), 0xC0);
+    cartridge.write_byte(0xA000, 0x01);
+
+    cartridge.write_byte(0x0000, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0x42);
+}
+
+fn huc3_command(cartridge: &mut Cartridge, command: u8) -> u8
+{
+    cartridge.write_byte(0x0000, 0x0B);
+    cartridge.write_byte(0xA000, command);
+    cartridge.write_byte(0x0000, 0x0C);
+    cartridge.read_byte(0xA000)
+}
+
+// Minutes of the day and days, stored in the first 6 nibbles.
+fn huc3_time(cartridge: &mut Cartridge) -> (u16, u16)
+{
+    huc3_command(cartridge, 0x60);
+    huc3_command(cartridge, 0x40);
+    huc3_command(cartridge, 0x50);
+
+    let nibbles: Vec<u16> = (0..6)
+        .map(|_| (huc3_command(cartridge, 0x10) & 0x0F) as u16)
+        .collect();
+    let value = |nibbles: &[u16]| nibbles[0] | nibbles[1] << 4 | nibbles[2] << 8;
+
+    (value(&nibbles[..3]), value(&nibbles[3..]))
+}
+
+#[test]
+fn huc3_time_is_set_through_the_memory()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0xFE, &clock);
+
+    // 1439 minutes (0x59F) and 300 days (0x12C).
+    huc3_command(&mut cartridge, 0x40);
+    huc3_command(&mut cartridge, 0x50);
+    for nibble in [0x0F, 0x09, 0x05, 0x0C, 0x02, 0x01] {
+        huc3_command(&mut cartridge, 0x30 | nibble);
+    }
+    huc3_command(&mut cartridge, 0x61);
+    assert_eq!(huc3_time(&mut cartridge), (1439, 300));
+
+    // The last minute of the day rolls over to the next one.
+    clock.advance(60);
+    assert_eq!(huc3_time(&mut cartridge), (0, 301));
+}
+
+#[test]
+fn huc3_answers_the_status_check()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0xFE, &clock);
+
+    assert_eq!(huc3_command(&mut cartridge, 0x62), 0x61);
+
+    cartridge.write_byte(0x0000, 0x0D);
+    assert_eq!(cartridge.read_byte(0xA000), 0x01);
+}
@@ -0,0 +1,131 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, Mapper, MemoryAccess, NINTENDO_LOGO, Validation};
+
+const BANK: usize = 0x4000;
+
+// Rom with the given number of banks, every byte holds the number
+// of its bank.
+fn rom(banks: u8, rom_size: u8) -> Vec<u8>
+{
+    let mut rom: Vec<u8> = (0..banks).flat_map(|bank| [bank; BANK]).collect();
+    rom[0x147] = 0x03;
+    rom[0x148] = rom_size;
+
+    rom
+}
+
+fn cartridge(rom: Vec<u8>) -> Cartridge
+{
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc1);
+
+    cartridge
+}
+
+#[test]
+fn mode_1_remaps_the_lower_area_of_large_roms()
+{
+    let mut cartridge = cartridge(rom(128, 0x06));
+
+    cartridge.write_byte(0x4000, 0x02);
+    assert_eq!(cartridge.read_byte(0x0000), 0x00);
+    assert_eq!(cartridge.read_byte(0x4000), 0x41);
+
+    cartridge.write_byte(0x6000, 0x01);
+    assert_eq!(cartridge.read_byte(0x0000), 0x40);
+    assert_eq!(cartridge.read_byte(0x4000), 0x41);
+
+    cartridge.write_byte(0x6000, 0x00);
+    assert_eq!(cartridge.read_byte(0x0000), 0x00);
+}
+
+#[test]
+fn zero_in_the_lower_bits_selects_the_next_bank()
+{
+    let mut cartridge = cartridge(rom(128, 0x06));
+
+    // Only the lower 5 bits are checked for zero, so the banks 0x20,
+    // 0x40 and 0x60 can never be mapped to the upper area.
+    for (upper, bank) in [(0x00, 0x01), (0x01, 0x21), (0x02, 0x41), (0x03, 0x61)] {
+        cartridge.write_byte(0x4000, upper);
+        cartridge.write_byte(0x2000, 0x00);
+        assert_eq!(cartridge.read_byte(0x4000), bank);
+
+        cartridge.write_byte(0x2000, 0x20);
+        assert_eq!(cartridge.read_byte(0x4000), bank);
+    }
+}
+
+#[test]
+fn mode_1_switches_the_ram_banks()
+{
+    let mut rom = rom(4, 0x01);
+    rom[0x149] = 0x03;
+    let mut cartridge = cartridge(rom);
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0xA000, 0x10);
+    cartridge.write_byte(0x6000, 0x01);
+    cartridge.write_byte(0x4000, 0x02);
+    cartridge.write_byte(0xA000, 0x12);
+    assert_eq!(cartridge.read_byte(0xA000), 0x12);
+
+    // In mode 0 the bank 0 is always mapped.
+    cartridge.write_byte(0x6000, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0x10);
+
+    cartridge.write_byte(0x6000, 0x01);
+    assert_eq!(cartridge.read_byte(0xA000), 0x12);
+}
+
+#[test]
+fn ram_is_not_switched_without_32_kib()
+{
+    let mut rom = rom(4, 0x01);
+    rom[0x149] = 0x02;
+    let mut cartridge = cartridge(rom);
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0xA000, 0x10);
+    cartridge.write_byte(0x6000, 0x01);
+    cartridge.write_byte(0x4000, 0x02);
+    assert_eq!(cartridge.read_byte(0xA000), 0x10);
+}
+
+#[test]
+fn multicart_shifts_the_upper_bits_by_4()
+{
+    // The second game of the collection starts at the bank 0x10.
+    let mut rom = rom(64, 0x05);
+    rom[0x10 * BANK + 0x104..0x10 * BANK + 0x134].copy_from_slice(&NINTENDO_LOGO);
+    let mut cartridge = cartridge(rom);
+
+    cartridge.write_byte(0x4000, 0x01);
+    cartridge.write_byte(0x2000, 0x02);
+    assert_eq!(cartridge.read_byte(0x4000), 0x12);
+
+    // The highest bit of the lower register is not connected.
+    cartridge.write_byte(0x2000, 0x12);
+    assert_eq!(cartridge.read_byte(0x4000), 0x12);
+
+    cartridge.write_byte(0x6000, 0x01);
+    assert_eq!(cartridge.read_byte(0x0000), 0x10);
+
+    cartridge.write_byte(0x4000, 0x03);
+    assert_eq!(cartridge.read_byte(0x0000), 0x30);
+}
+
+#[test]
+fn rom_without_a_second_logo_is_not_a_multicart()
+{
+    let mut cartridge = cartridge(rom(64, 0x05));
+
+    cartridge.write_byte(0x4000, 0x01);
+    cartridge.write_byte(0x2000, 0x02);
+    assert_eq!(cartridge.read_byte(0x4000), 0x22);
+}
@@ -0,0 +1,70 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, Mapper, MemoryAccess, Validation};
+
+const BANK: usize = 0x4000;
+
+// `MBC2` cartridge with 16 banks, every byte holds the number of its bank.
+fn cartridge() -> Cartridge
+{
+    let mut rom: Vec<u8> = (0..16u8).flat_map(|bank| [bank; BANK]).collect();
+    rom[0x147] = 0x05;
+    rom[0x148] = 0x03;
+
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc2);
+
+    cartridge
+}
+
+#[test]
+fn bit_8_of_the_address_selects_the_register()
+{
+    let mut cartridge = cartridge();
+
+    // With bit 8 clear the write only enables the ram.
+    cartridge.write_byte(0x2000, 0x0A);
+    assert_eq!(cartridge.read_byte(0x4000), 0x01);
+    cartridge.write_byte(0xA000, 0x05);
+    assert_eq!(cartridge.read_byte(0xA000), 0xF5);
+
+    // With bit 8 set the write only selects the rom bank.
+    cartridge.write_byte(0x0100, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0xF5);
+    assert_eq!(cartridge.read_byte(0x4000), 0x01);
+
+    cartridge.write_byte(0x3F00, 0x1F);
+    assert_eq!(cartridge.read_byte(0x4000), 0x0F);
+
+    cartridge.write_byte(0x0000, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0xFF);
+}
+
+#[test]
+fn ram_stores_only_the_lower_nibble()
+{
+    let mut cartridge = cartridge();
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0xA000, 0xAB);
+    cartridge.write_byte(0xA1FF, 0x3C);
+
+    assert_eq!(cartridge.read_byte(0xA000), 0xFB);
+    assert_eq!(cartridge.read_byte(0xA1FF), 0xFC);
+}
+
+#[test]
+fn ram_mirrors_across_the_whole_area()
+{
+    let mut cartridge = cartridge();
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0xA042, 0x07);
+
+    assert_eq!(cartridge.read_byte(0xA242), 0xF7);
+    assert_eq!(cartridge.read_byte(0xBE42), 0xF7);
+}
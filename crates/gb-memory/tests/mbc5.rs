@@ -0,0 +1,95 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, Mapper, MemoryAccess, Validation};
+
+const BANK: usize = 0x4000;
+
+// `MBC5` cartridge with 512 banks, each starting with its number.
+fn cartridge(cartridge_type: u8, ram_size: u8) -> Cartridge
+{
+    let mut rom = vec![0x00; 512 * BANK];
+    for bank in 0..512 {
+        rom[bank * BANK..bank * BANK + 2].copy_from_slice(&(bank as u16).to_le_bytes());
+    }
+    rom[0x147] = cartridge_type;
+    rom[0x148] = 0x08;
+    rom[0x149] = ram_size;
+
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc5);
+
+    cartridge
+}
+
+fn mapped_bank(cartridge: &Cartridge) -> u16
+{
+    u16::from_le_bytes([cartridge.read_byte(0x4000), cartridge.read_byte(0x4001)])
+}
+
+#[test]
+fn rom_bank_has_9_bits()
+{
+    let mut cartridge = cartridge(0x19, 0x00);
+
+    cartridge.write_byte(0x2000, 0x42);
+    assert_eq!(mapped_bank(&cartridge), 0x042);
+
+    cartridge.write_byte(0x3000, 0x01);
+    assert_eq!(mapped_bank(&cartridge), 0x142);
+
+    // The lower bits do not touch the 9th one.
+    cartridge.write_byte(0x2000, 0xFF);
+    assert_eq!(mapped_bank(&cartridge), 0x1FF);
+
+    cartridge.write_byte(0x3000, 0xFE);
+    assert_eq!(mapped_bank(&cartridge), 0x0FF);
+}
+
+#[test]
+fn bank_0_can_be_mapped_to_the_upper_area()
+{
+    let mut cartridge = cartridge(0x19, 0x00);
+
+    cartridge.write_byte(0x2000, 0x00);
+    assert_eq!(mapped_bank(&cartridge), 0x000);
+}
+
+#[test]
+fn bit_3_of_the_ram_bank_drives_the_motor()
+{
+    let mut cartridge = cartridge(0x1D, 0x03);
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0x4000, 0x01);
+    cartridge.write_byte(0xA000, 0x11);
+    assert!(!cartridge.is_rumbling());
+
+    // The motor does not change the selected bank.
+    cartridge.write_byte(0x4000, 0x09);
+    assert!(cartridge.is_rumbling());
+    assert_eq!(cartridge.read_byte(0xA000), 0x11);
+
+    cartridge.write_byte(0x4000, 0x01);
+    assert!(!cartridge.is_rumbling());
+}
+
+#[test]
+fn bit_3_selects_the_ram_bank_without_a_motor()
+{
+    let mut cartridge = cartridge(0x1A, 0x04);
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge.write_byte(0x4000, 0x00);
+    cartridge.write_byte(0xA000, 0x11);
+    cartridge.write_byte(0x4000, 0x08);
+    cartridge.write_byte(0xA000, 0x88);
+
+    assert!(!cartridge.is_rumbling());
+    assert_eq!(cartridge.read_byte(0xA000), 0x88);
+    cartridge.write_byte(0x4000, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0x11);
+}
@@ -0,0 +1,62 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, Mapper, MemoryAccess, Validation};
+
+// `MBC6` cartridge with the flash mapped to both halves. The first one
+// maps the offset 0x4000, so the unlock addresses of the flash are at
+// 0x5555 and 0x6AAA.
+fn cartridge() -> Cartridge
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = 0x20;
+
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc6);
+
+    cartridge.write_byte(0x0C00, 0x01);
+    cartridge.write_byte(0x2000, 0x02);
+    cartridge.write_byte(0x2800, 0x08);
+    cartridge.write_byte(0x3000, 0x01);
+    cartridge.write_byte(0x3800, 0x08);
+
+    cartridge
+}
+
+fn flash_command(cartridge: &mut Cartridge, command: u8)
+{
+    cartridge.write_byte(0x5555, 0xAA);
+    cartridge.write_byte(0x6AAA, 0x55);
+    cartridge.write_byte(0x5555, command);
+}
+
+#[test]
+fn autoselect_and_reset_do_not_need_the_write_enable()
+{
+    let mut cartridge = cartridge();
+
+    flash_command(&mut cartridge, 0x90);
+    assert_eq!(cartridge.read_byte(0x4000), 0xC2);
+    assert_eq!(cartridge.read_byte(0x4001), 0x81);
+
+    cartridge.write_byte(0x4000, 0xF0);
+    assert_eq!(cartridge.read_byte(0x4000), 0xFF);
+}
+
+#[test]
+fn program_needs_the_write_enable()
+{
+    let mut cartridge = cartridge();
+
+    flash_command(&mut cartridge, 0xA0);
+    cartridge.write_byte(0x4000, 0x42);
+    assert_eq!(cartridge.read_byte(0x4000), 0xFF);
+
+    cartridge.write_byte(0x1000, 0x01);
+    flash_command(&mut cartridge, 0xA0);
+    cartridge.write_byte(0x4000, 0x42);
+    assert_eq!(cartridge.read_byte(0x4000), 0x42);
+}
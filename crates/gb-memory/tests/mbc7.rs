@@ -0,0 +1,117 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, Mapper, MemoryAccess, Validation};
+
+// Lines of the eeprom in the register at 0xA080.
+const CS: u8 = 0x80;
+const CLK: u8 = 0x40;
+const DI: u8 = 0x02;
+const DO: u8 = 0x01;
+
+fn cartridge() -> Cartridge
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = 0x22;
+
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc7);
+
+    // Both of the enable registers have to be set.
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x4000, 0x40);
+
+    cartridge
+}
+
+// Shifts the lowest `count` bits of the value into the eeprom, the most
+// significant one first, and returns the bits shifted out meanwhile.
+fn clock_bits(cartridge: &mut Cartridge, value: u32, count: u32) -> u32
+{
+    (0..count).rev().fold(0, |out, bit| {
+        let di = match value >> bit & 1 {
+            1 => DI,
+            _ => 0x00,
+        };
+        cartridge.write_byte(0xA080, CS | di);
+        cartridge.write_byte(0xA080, CS | CLK | di);
+
+        (out << 1) | (cartridge.read_byte(0xA080) & DO) as u32
+    })
+}
+
+// Sends the start bit, the opcode and the address, then deselects the
+// chip once the data was shifted.
+fn command(cartridge: &mut Cartridge, opcode: u32, addr: u32, data: Option<u16>) -> u16
+{
+    clock_bits(cartridge, 0b100 | opcode, 3);
+    clock_bits(cartridge, addr, 8);
+
+    let out = match data {
+        Some(data) => clock_bits(cartridge, data as u32, 16),
+        None => clock_bits(cartridge, 0, 16),
+    };
+    cartridge.write_byte(0xA080, 0x00);
+
+    out as u16
+}
+
+const EWEN: (u32, u32) = (0b00, 0xC0);
+const WRITE: u32 = 0b01;
+const READ: u32 = 0b10;
+
+#[test]
+fn eeprom_word_is_written_and_read_back()
+{
+    let mut cartridge = cartridge();
+
+    command(&mut cartridge, EWEN.0, EWEN.1, None);
+    command(&mut cartridge, WRITE, 0x05, Some(0xBEEF));
+
+    assert_eq!(command(&mut cartridge, READ, 0x05, None), 0xBEEF);
+    assert_eq!(command(&mut cartridge, READ, 0x06, None), 0xFFFF);
+}
+
+#[test]
+fn eeprom_ignores_writes_until_enabled()
+{
+    let mut cartridge = cartridge();
+
+    command(&mut cartridge, WRITE, 0x05, Some(0xBEEF));
+    assert_eq!(command(&mut cartridge, READ, 0x05, None), 0xFFFF);
+}
+
+fn latched(cartridge: &Cartridge) -> (u16, u16)
+{
+    let read = |addr| cartridge.read_byte(addr) as u16;
+
+    (
+        read(0xA030) << 8 | read(0xA020),
+        read(0xA050) << 8 | read(0xA040),
+    )
+}
+
+#[test]
+fn accelerometer_is_latched_only_after_an_erase()
+{
+    let mut cartridge = cartridge();
+    cartridge.set_tilt(0.5, -0.25);
+
+    cartridge.write_byte(0xA000, 0x55);
+    cartridge.write_byte(0xA010, 0xAA);
+    assert_eq!(latched(&cartridge), (0x81D0 + 56, 0x81D0 - 28));
+
+    // The sample stays until it is erased.
+    cartridge.set_tilt(0.0, 0.0);
+    cartridge.write_byte(0xA010, 0xAA);
+    assert_eq!(latched(&cartridge), (0x81D0 + 56, 0x81D0 - 28));
+
+    cartridge.write_byte(0xA000, 0x55);
+    assert_eq!(latched(&cartridge), (0x8000, 0x8000));
+
+    cartridge.write_byte(0xA010, 0xAA);
+    assert_eq!(latched(&cartridge), (0x81D0, 0x81D0));
+}
@@ -0,0 +1,106 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{
+    Cartridge, CartridgeWarning, Header, Mapper, MemoryAccess, NINTENDO_LOGO, Validation,
+};
+
+const BANK: usize = 0x4000;
+// The header lives in the menu, in the last 32 KiB of the image.
+const MENU: usize = 6 * BANK;
+
+// Multicart with 8 banks, every byte holds the number of its bank.
+fn multicart() -> Vec<u8>
+{
+    let mut rom: Vec<u8> = (0..8u8).flat_map(|bank| [bank; BANK]).collect();
+    rom[MENU + 0x104..MENU + 0x134].copy_from_slice(&NINTENDO_LOGO);
+    rom[MENU + 0x147] = 0x0B;
+    rom[MENU + 0x148] = 0x02;
+    rom[MENU + 0x14D] = Header::compute_header_checksum(&rom[MENU..]);
+
+    // The global checksum covers every game, not only the menu.
+    let checksum = rom
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !(MENU + 0x14E..MENU + 0x150).contains(idx))
+        .fold(0u16, |acc, (_, byte)| acc.wrapping_add(*byte as u16));
+    rom[MENU + 0x14E..MENU + 0x150].copy_from_slice(&checksum.to_be_bytes());
+
+    rom
+}
+
+fn cartridge() -> Cartridge
+{
+    Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(multicart())
+        .unwrap()
+}
+
+#[test]
+fn global_checksum_covers_the_whole_rom()
+{
+    let cartridge = cartridge();
+
+    assert_eq!(cartridge.mapper(), Mapper::Mmm01);
+    assert!(cartridge.validation().global_checksum.is_valid());
+    assert!(
+        !cartridge
+            .warnings()
+            .iter()
+            .any(|warning| matches!(warning, CartridgeWarning::GlobalChecksum { .. }))
+    );
+}
+
+#[test]
+fn starts_with_the_menu_mapped()
+{
+    let cartridge = cartridge();
+
+    assert_eq!(cartridge.read_byte(0x0000), 0x06);
+    assert_eq!(cartridge.read_byte(0x4000), 0x07);
+}
+
+#[test]
+fn mapped_game_can_switch_only_the_unmasked_bits()
+{
+    let mut cartridge = cartridge();
+
+    // Bits 2 - 5 of the mode register mask the bits 1 - 4 of the bank,
+    // so only the lowest bit stays writable for the game.
+    cartridge.write_byte(0x2000, 0x02);
+    cartridge.write_byte(0x6000, 0x3C);
+    cartridge.write_byte(0x0000, 0x40);
+
+    assert_eq!(cartridge.read_byte(0x0000), 0x02);
+    assert_eq!(cartridge.read_byte(0x4000), 0x03);
+
+    cartridge.write_byte(0x2000, 0x1C);
+    assert_eq!(cartridge.read_byte(0x4000), 0x03);
+
+    cartridge.write_byte(0x2000, 0x01);
+    assert_eq!(cartridge.read_byte(0x4000), 0x03);
+}
+
+#[test]
+fn mmm01_type_without_a_menu_header_is_ignored()
+{
+    // Plain `MBC1` rom which happens to have the MMM01 type byte where
+    // the header of the menu would be.
+    let mut rom: Vec<u8> = (0..8u8).flat_map(|bank| [bank; BANK]).collect();
+    rom[0x147] = 0x01;
+    rom[0x148] = 0x02;
+    rom[MENU + 0x147] = 0x0B;
+
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom.clone())
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc1);
+    assert_eq!(cartridge.read_byte(0x0000), 0x00);
+    assert_eq!(Header::offset(&rom), 0);
+
+    // The logo alone is not enough either.
+    rom[MENU + 0x104..MENU + 0x134].copy_from_slice(&NINTENDO_LOGO);
+    assert_eq!(Header::offset(&rom), 0);
+}
@@ -0,0 +1,128 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{
+    Cartridge, CartridgeBuilder, CartridgeError, CartridgeOptions, CartridgeWarning, CgbFlag,
+    Mapper, MemoryAccess, Validation,
+};
+
+const BANK: usize = 0x4000;
+
+// `MBC1` rom with the given number of banks, every byte holds
+// the number of its bank.
+fn rom(banks: u8) -> Vec<u8>
+{
+    let mut rom: Vec<u8> = (0..banks).flat_map(|bank| [bank; BANK]).collect();
+    rom[0x147] = 0x02;
+    rom[0x148] = 0x02;
+
+    rom
+}
+
+fn builder() -> CartridgeBuilder
+{
+    Cartridge::builder().validation(Validation::Lenient)
+}
+
+fn is_truncated(cartridge: &Cartridge) -> bool
+{
+    cartridge
+        .warnings()
+        .iter()
+        .any(|warning| matches!(warning, CartridgeWarning::RomTruncated { .. }))
+}
+
+#[test]
+fn forced_rom_size_pads_the_image()
+{
+    let mut cartridge = builder().rom_banks(4).from_bytes(rom(2)).unwrap();
+
+    assert_eq!(cartridge.header().rom_banks, 4);
+    assert!(!is_truncated(&cartridge));
+
+    cartridge.write_byte(0x2000, 0x03);
+    assert_eq!(cartridge.read_byte(0x4000), 0xFF);
+}
+
+#[test]
+fn forced_rom_size_trims_the_image()
+{
+    let mut cartridge = builder().rom_banks(2).from_bytes(rom(8)).unwrap();
+
+    assert_eq!(cartridge.header().rom_banks, 2);
+
+    // Only the banks which were kept are left to wrap around.
+    cartridge.write_byte(0x2000, 0x03);
+    assert_eq!(cartridge.read_byte(0x4000), 0x01);
+}
+
+#[test]
+fn forced_rom_size_is_limited()
+{
+    let result = builder().rom_banks(1024).from_bytes(rom(2));
+    assert!(matches!(result, Err(CartridgeError::RomTooLarge(_))));
+}
+
+#[test]
+fn forced_battery_replaces_the_header_one()
+{
+    let path = std::env::temp_dir().join(format!("gb-memory-options-{}.sav", std::process::id()));
+
+    let cartridge = builder()
+        .battery(true)
+        .save_path(&path)
+        .from_bytes(rom(8))
+        .unwrap();
+    assert!(cartridge.has_battery());
+    assert_eq!(cartridge.save_path(), Some(path.as_path()));
+
+    let mut rom = rom(8);
+    rom[0x147] = 0x03;
+
+    let cartridge = builder()
+        .battery(false)
+        .save_path(&path)
+        .from_bytes(rom)
+        .unwrap();
+    assert!(!cartridge.has_battery());
+    assert_eq!(cartridge.save_path(), None);
+}
+
+#[test]
+fn forced_cgb_mode_replaces_the_header_one()
+{
+    let cartridge = builder().cgb(CgbFlag::CgbOnly).from_bytes(rom(8)).unwrap();
+    assert_eq!(cartridge.header().cgb, CgbFlag::CgbOnly);
+
+    let mut rom = rom(8);
+    rom[0x143] = 0xC0;
+
+    let cartridge = builder().cgb(CgbFlag::DmgOnly).from_bytes(rom).unwrap();
+    assert_eq!(cartridge.header().cgb, CgbFlag::DmgOnly);
+}
+
+#[test]
+fn options_replace_the_previous_overrides()
+{
+    let options = CartridgeOptions {
+        ram_banks: Some(4),
+        ..CartridgeOptions::new()
+    };
+    let cartridge = builder()
+        .mapper(Mapper::Mbc5)
+        .options(options)
+        .from_bytes(rom(8))
+        .unwrap();
+
+    assert_eq!(cartridge.mapper(), Mapper::Mbc1);
+    assert_eq!(cartridge.header().ram_banks, 4);
+}
+
+#[test]
+fn header_size_is_kept_without_overrides()
+{
+    let cartridge = builder().from_bytes(rom(2)).unwrap();
+
+    assert_eq!(cartridge.header().rom_banks, 8);
+    assert!(is_truncated(&cartridge));
+}
@@ -0,0 +1,285 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{CartridgeError, MAX_ROM_BANKS, Patch, PatchFormat, ROM_BANK_SIZE, RomHashes};
+
+const UPS_MAGIC: &[u8] = b"UPS1";
+const BPS_MAGIC: &[u8] = b"BPS1";
+
+// Fixtures made with an independent encoder. The `UPS` and `BPS`
+// ones turn the source into the same target, `IPS` only writes the
+// "GBMU" and fills the 4 bytes after the source.
+const IPS: [u8; 25] = [
+    0x50, 0x41, 0x54, 0x43, 0x48, 0x00, 0x00, 0x04, 0x00, 0x04, 0x47, 0x42, 0x4D, 0x55, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x04, 0xEE, 0x45, 0x4F, 0x46,
+];
+const UPS: [u8; 42] = [
+    0x55, 0x50, 0x53, 0x31, 0xA0, 0xA8, 0x84, 0x43, 0x47, 0x4B, 0x52, 0x00, 0x8F, 0x18, 0x18, 0x18,
+    0x18, 0x18, 0x18, 0x18, 0x18, 0x47, 0x42, 0x4D, 0x55, 0x08, 0x09, 0x0A, 0x0B, 0x00, 0x8A, 0x7E,
+    0x26, 0x91, 0xA7, 0x55, 0xC7, 0x7B, 0x0D, 0xD5, 0xA5, 0x3A,
+];
+// Uses every action: source read, target read, source copy and an
+// overlapping target copy.
+const BPS: [u8; 30] = [
+    0x42, 0x50, 0x53, 0x31, 0xA0, 0xA8, 0x80, 0x8C, 0x8D, 0x47, 0x42, 0x4D, 0x55, 0xBC, 0x9E, 0x80,
+    0x9F, 0x88, 0x8A, 0x7E, 0x26, 0x91, 0xA7, 0x55, 0xC7, 0x7B, 0xC6, 0x5D, 0x5D, 0xD6,
+];
+
+fn source() -> Vec<u8>
+{
+    (0..32).collect()
+}
+
+fn target() -> Vec<u8>
+{
+    let mut target = source();
+    target[4..8].copy_from_slice(b"GBMU");
+    target[24..32].copy_from_slice(&source()[..8]);
+    target.extend_from_within(4..12);
+
+    target
+}
+
+fn varint(mut value: usize) -> Vec<u8>
+{
+    let mut bytes = Vec::new();
+
+    loop {
+        let part = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes.push(0x80 | part);
+            return bytes;
+        }
+        bytes.push(part);
+        value -= 1;
+    }
+}
+
+fn crc32(data: &[u8]) -> u32
+{
+    RomHashes::new(data).crc32
+}
+
+// Appends the checksums of the source, the target and the patch.
+fn with_checksums(mut patch: Vec<u8>, source_crc: u32, target_crc: u32) -> Patch
+{
+    patch.extend_from_slice(&source_crc.to_le_bytes());
+    patch.extend_from_slice(&target_crc.to_le_bytes());
+    patch.extend_from_slice(&crc32(&patch).to_le_bytes());
+
+    Patch::new(patch).unwrap()
+}
+
+// Appends a valid footer, so the malformed part is what gets tested.
+fn with_footer(patch: Vec<u8>, source: &[u8]) -> Patch
+{
+    with_checksums(patch, crc32(source), crc32(&target()))
+}
+
+fn bps_header(target_len: usize) -> Vec<u8>
+{
+    [BPS_MAGIC, &varint(32), &varint(target_len), &varint(0)].concat()
+}
+
+#[test]
+fn detects_the_format()
+{
+    let format = |data: &[u8]| Patch::new(data.to_vec()).map(|patch| patch.format());
+
+    assert_eq!(format(&IPS).unwrap(), PatchFormat::Ips);
+    assert_eq!(format(&UPS).unwrap(), PatchFormat::Ups);
+    assert_eq!(format(&BPS).unwrap(), PatchFormat::Bps);
+    assert!(matches!(format(b"NOPE"), Err(CartridgeError::Patch(_))));
+}
+
+#[test]
+fn applies_ips()
+{
+    let mut expected = source();
+    expected[4..8].copy_from_slice(b"GBMU");
+    expected.extend_from_slice(&[0xEE; 4]);
+
+    let patch = Patch::new(IPS.to_vec()).unwrap();
+    assert_eq!(patch.apply(&source()).unwrap(), expected);
+}
+
+#[test]
+fn ips_truncates_to_the_size_after_the_end_marker()
+{
+    let patch = Patch::new([&IPS[..], &[0x00, 0x00, 0x10]].concat()).unwrap();
+    assert_eq!(patch.apply(&source()).unwrap(), &target()[..16]);
+}
+
+#[test]
+fn rejects_truncated_ips()
+{
+    let patch = Patch::new(IPS[..12].to_vec()).unwrap();
+    assert!(matches!(
+        patch.apply(&source()),
+        Err(CartridgeError::Patch(_))
+    ));
+}
+
+#[test]
+fn applies_ups()
+{
+    let patch = Patch::new(UPS.to_vec()).unwrap();
+    assert_eq!(patch.apply(&source()).unwrap(), target());
+}
+
+#[test]
+fn applies_bps()
+{
+    let patch = Patch::new(BPS.to_vec()).unwrap();
+    assert_eq!(patch.apply(&source()).unwrap(), target());
+}
+
+#[test]
+fn rejects_other_source()
+{
+    let mut rom = source();
+    rom[0] = 0xFF;
+
+    for data in [&UPS[..], &BPS[..]] {
+        let patch = Patch::new(data.to_vec()).unwrap();
+        assert!(matches!(
+            patch.apply(&rom),
+            Err(CartridgeError::PatchSourceMismatch { .. })
+        ));
+    }
+}
+
+#[test]
+fn rejects_corrupted_patch()
+{
+    for data in [&UPS[..], &BPS[..]] {
+        let mut data = data.to_vec();
+        data[10] ^= 0x01;
+
+        let patch = Patch::new(data).unwrap();
+        assert!(matches!(
+            patch.apply(&source()),
+            Err(CartridgeError::PatchCorrupted { .. })
+        ));
+    }
+}
+
+#[test]
+fn rejects_truncated_patch()
+{
+    // Too short to even hold the footer.
+    for data in [&UPS[..8], &BPS[..8]] {
+        let patch = Patch::new(data.to_vec()).unwrap();
+        assert!(matches!(
+            patch.apply(&source()),
+            Err(CartridgeError::Patch(_))
+        ));
+    }
+    // Cut in the middle of the last hunk and of the last action.
+    let ups = with_footer(UPS[..UPS.len() - 13].to_vec(), &source());
+    let bps = with_footer(BPS[..BPS.len() - 13].to_vec(), &source());
+
+    for patch in [ups, bps] {
+        assert!(matches!(
+            patch.apply(&source()),
+            Err(CartridgeError::Patch(_))
+        ));
+    }
+}
+
+#[test]
+fn rejects_target_with_other_checksum()
+{
+    let target_crc = crc32(&target()) ^ 0x01;
+
+    for data in [&UPS[..], &BPS[..]] {
+        let body = data[..data.len() - 12].to_vec();
+        let patch = with_checksums(body, crc32(&source()), target_crc);
+
+        assert!(matches!(
+            patch.apply(&source()),
+            Err(CartridgeError::PatchTargetMismatch { expected, .. }) if expected == target_crc
+        ));
+    }
+}
+
+#[test]
+fn rejects_target_larger_than_any_rom()
+{
+    let len = MAX_ROM_BANKS * ROM_BANK_SIZE + 1;
+
+    let ups = with_footer([UPS_MAGIC, &varint(32), &varint(len)].concat(), &source());
+    let bps = with_footer(bps_header(len), &source());
+
+    for patch in [ups, bps] {
+        assert!(matches!(
+            patch.apply(&source()),
+            Err(CartridgeError::RomTooLarge(_))
+        ));
+    }
+}
+
+#[test]
+fn rejects_number_out_of_range()
+{
+    // Never terminated, the value grows past `usize::MAX`.
+    let ups = with_footer([UPS_MAGIC, &[0x7F; 12]].concat(), &source());
+    // Metadata longer than the address space.
+    let bps = with_footer(
+        [BPS_MAGIC, &varint(32), &varint(40), &varint(usize::MAX)].concat(),
+        &source(),
+    );
+    for patch in [ups, bps] {
+        assert!(matches!(
+            patch.apply(&source()),
+            Err(CartridgeError::Patch(_))
+        ));
+    }
+}
+
+#[test]
+fn rejects_hunk_past_the_target()
+{
+    let patch = with_footer(
+        [
+            UPS_MAGIC,
+            &varint(32),
+            &varint(40),
+            &varint(usize::MAX - 1),
+            &[0x00],
+        ]
+        .concat(),
+        &source(),
+    );
+    assert!(matches!(
+        patch.apply(&source()),
+        Err(CartridgeError::Patch(_))
+    ));
+}
+
+#[test]
+fn rejects_copy_offset_out_of_range()
+{
+    // A valid source copy moves the offset to 8, the second one
+    // would move it past `isize::MAX`.
+    let far = (isize::MAX as usize) << 1;
+    let source_copy = [&varint(7 << 2 | 2)[..], &varint(0)].concat();
+    let overflow = [&varint(7 << 2 | 2)[..], &varint(far)].concat();
+
+    let patch = with_footer([bps_header(40), source_copy, overflow].concat(), &source());
+    assert!(matches!(
+        patch.apply(&source()),
+        Err(CartridgeError::Patch(_))
+    ));
+
+    // Copying from before the beginning of the target.
+    let target_copy = [&varint(7 << 2 | 3)[..], &varint(1 << 1 | 1)].concat();
+
+    let patch = with_footer([bps_header(40), target_copy].concat(), &source());
+    assert!(matches!(
+        patch.apply(&source()),
+        Err(CartridgeError::Patch(_))
+    ));
+}
@@ -0,0 +1,83 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, CartridgeWarning, Validation};
+
+const BANK: usize = 0x4000;
+
+// Rom declaring 2 banks, with the given number of banks, none of them
+// a mirror or a filler.
+fn rom(banks: usize) -> Vec<u8>
+{
+    let mut rom: Vec<u8> = (0..banks * BANK).map(|idx| (idx / 0x100) as u8).collect();
+    rom[0x147] = 0x01;
+    rom[0x148] = 0x00;
+
+    rom
+}
+
+// Warnings about the size of the rom, the rest is not relevant here.
+fn size_warnings(rom: Vec<u8>) -> Vec<CartridgeWarning>
+{
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .unwrap();
+
+    cartridge
+        .warnings()
+        .iter()
+        .filter(|warning| {
+            matches!(
+                warning,
+                CartridgeWarning::RomTruncated { .. }
+                    | CartridgeWarning::RomOverdumped { .. }
+                    | CartridgeWarning::RomLargerThanDeclared { .. }
+            )
+        })
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn odd_sized_rom_larger_than_declared_is_not_truncated()
+{
+    assert_eq!(
+        size_warnings(rom(3)),
+        [CartridgeWarning::RomLargerThanDeclared {
+            expected: 2 * BANK,
+            actual: 3 * BANK,
+        }]
+    );
+}
+
+#[test]
+fn short_rom_is_truncated()
+{
+    let mut rom = rom(4);
+    rom[0x148] = 0x02;
+    rom.truncate(3 * BANK);
+
+    assert_eq!(
+        size_warnings(rom),
+        [CartridgeWarning::RomTruncated {
+            expected: 8 * BANK,
+            actual: 3 * BANK,
+        }]
+    );
+}
+
+#[test]
+fn mirrored_rom_is_overdumped()
+{
+    let mut rom = rom(2);
+    rom.extend_from_within(..);
+
+    assert_eq!(
+        size_warnings(rom),
+        [CartridgeWarning::RomOverdumped {
+            expected: 2 * BANK,
+            actual: 4 * BANK,
+        }]
+    );
+}
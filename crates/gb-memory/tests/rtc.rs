@@ -0,0 +1,231 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use gb_memory::{Cartridge, Clock, Mapper, MemoryAccess, Validation};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+// Clock moved forward by the test, the copies share the same time.
+#[derive(Clone, Default)]
+struct FakeClock(Arc<AtomicU64>);
+
+impl FakeClock
+{
+    fn advance(&self, seconds: u64)
+    {
+        self.0.fetch_add(seconds, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FakeClock
+{
+    fn now(&self) -> u64
+    {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+fn cartridge(cartridge_type: u8, clock: &FakeClock) -> Cartridge
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = cartridge_type;
+
+    Cartridge::builder()
+        .validation(Validation::Lenient)
+        .clock(Box::new(clock.clone()))
+        .from_bytes(rom)
+        .unwrap()
+}
+
+// Registers of the `MBC3` clock after latching: seconds, minutes,
+// hours, lower and upper day counter.
+fn latch(cartridge: &mut Cartridge) -> [u8; 5]
+{
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x6000, 0x00);
+    cartridge.write_byte(0x6000, 0x01);
+
+    [0x08, 0x09, 0x0A, 0x0B, 0x0C].map(|reg| {
+        cartridge.write_byte(0x4000, reg);
+        cartridge.read_byte(0xA000)
+    })
+}
+
+fn write_register(cartridge: &mut Cartridge, reg: u8, val: u8)
+{
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x4000, reg);
+    cartridge.write_byte(0xA000, val);
+}
+
+#[test]
+fn mbc3_seconds_roll_over_to_days()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0x10, &clock);
+
+    clock.advance(59);
+    assert_eq!(latch(&mut cartridge), [59, 0, 0, 0, 0]);
+
+    clock.advance(1);
+    assert_eq!(latch(&mut cartridge), [0, 1, 0, 0, 0]);
+
+    clock.advance(59 * MINUTE);
+    assert_eq!(latch(&mut cartridge), [0, 0, 1, 0, 0]);
+
+    clock.advance(23 * HOUR + 5);
+    assert_eq!(latch(&mut cartridge), [5, 0, 0, 1, 0]);
+
+    // The 9th bit of the day counter lives in the upper register.
+    clock.advance(255 * DAY);
+    assert_eq!(latch(&mut cartridge), [5, 0, 0, 0, 0x01]);
+}
+
+#[test]
+fn mbc3_day_counter_overflow_sets_the_carry()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0x10, &clock);
+
+    clock.advance(511 * DAY);
+    assert_eq!(latch(&mut cartridge), [0, 0, 0, 0xFF, 0x01]);
+
+    clock.advance(DAY + 1);
+    assert_eq!(latch(&mut cartridge), [1, 0, 0, 0, 0x80]);
+
+    // The carry stays set until the game clears it.
+    clock.advance(DAY);
+    assert_eq!(latch(&mut cartridge), [1, 0, 0, 1, 0x80]);
+
+    write_register(&mut cartridge, 0x0C, 0x00);
+    assert_eq!(latch(&mut cartridge), [1, 0, 0, 1, 0x00]);
+}
+
+#[test]
+fn mbc3_halted_clock_does_not_count()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0x10, &clock);
+
+    clock.advance(10);
+    write_register(&mut cartridge, 0x0C, 0x40);
+
+    clock.advance(DAY);
+    assert_eq!(latch(&mut cartridge), [10, 0, 0, 0, 0x40]);
+
+    // The time set while halted is where the clock resumes from.
+    write_register(&mut cartridge, 0x09, 30);
+    write_register(&mut cartridge, 0x0C, 0x00);
+
+    clock.advance(5);
+    assert_eq!(latch(&mut cartridge), [15, 30, 0, 0, 0x00]);
+}
+
+#[test]
+fn mbc3_latched_registers_do_not_change()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0x10, &clock);
+
+    clock.advance(3);
+    latch(&mut cartridge);
+    clock.advance(3);
+
+    cartridge.write_byte(0x4000, 0x08);
+    assert_eq!(cartridge.read_byte(0xA000), 3);
+}
+
+#[test]
+fn mbc30_uses_the_clock()
+{
+    let clock = FakeClock::default();
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = 0x10;
+    // More than 4 banks of ram is only possible with MBC30.
+    rom[0x149] = 0x05;
+
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .clock(Box::new(clock.clone()))
+        .from_bytes(rom)
+        .unwrap();
+    assert_eq!(cartridge.mapper(), Mapper::Mbc30);
+
+    clock.advance(HOUR + 2);
+    assert_eq!(latch(&mut cartridge), [2, 0, 1, 0, 0]);
+}
+
+#[test]
+fn huc3_uses_the_clock()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0xFE, &clock);
+
+    clock.advance(2 * DAY + 61 * MINUTE + 59);
+
+    // Copy the time to the memory of the chip and read the first 6
+    // nibbles: the minutes of the day and the days.
+    cartridge.write_byte(0x0000, 0x0B);
+    cartridge.write_byte(0xA000, 0x60);
+    cartridge.write_byte(0xA000, 0x40);
+    cartridge.write_byte(0xA000, 0x50);
+
+    let nibbles: Vec<u8> = (0..6)
+        .map(|_| {
+            cartridge.write_byte(0x0000, 0x0B);
+            cartridge.write_byte(0xA000, 0x10);
+            cartridge.write_byte(0x0000, 0x0C);
+            cartridge.read_byte(0xA000) & 0x0F
+        })
+        .collect();
+    assert_eq!(nibbles, [0x0D, 0x03, 0x00, 0x02, 0x00, 0x00]);
+}
+
+// Reads the minutes or the hours of the `TAMA5` clock, both in BCD.
+// The bits set in `noise` are not connected and must be ignored.
+fn tama5_clock(cartridge: &mut Cartridge, addr: u8, noise: u8) -> u8
+{
+    let mut write = |reg: u8, val: u8| {
+        cartridge.write_byte(0xA001, noise | reg);
+        cartridge.write_byte(0xA000, val);
+    };
+    write(0x06, 0x04);
+    write(0x07, addr);
+
+    let mut read = |reg: u8| {
+        cartridge.write_byte(0xA001, noise | reg);
+        cartridge.read_byte(0xA000) & 0x0F
+    };
+    let low = read(0x0C);
+    let high = read(0x0D);
+
+    (high << 4) | low
+}
+
+#[test]
+fn tama5_uses_the_clock()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0xFD, &clock);
+
+    clock.advance(13 * HOUR + 42 * MINUTE);
+
+    assert_eq!(tama5_clock(&mut cartridge, 0x06, 0x00), 0x42);
+    assert_eq!(tama5_clock(&mut cartridge, 0x07, 0x00), 0x13);
+}
+
+#[test]
+fn tama5_register_select_has_4_bits()
+{
+    let clock = FakeClock::default();
+    let mut cartridge = cartridge(0xFD, &clock);
+
+    clock.advance(13 * HOUR + 42 * MINUTE);
+
+    assert_eq!(tama5_clock(&mut cartridge, 0x06, 0xF0), 0x42);
+}
@@ -0,0 +1,223 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use std::{fs, thread};
+
+use gb_memory::{Cartridge, Clock, MemoryAccess, Validation};
+
+// Directory removed together with the test data, even if the test fails.
+struct TempDir(PathBuf);
+
+impl TempDir
+{
+    fn new(name: &str) -> Self
+    {
+        let path = std::env::temp_dir().join(format!("gb-memory-{name}-{}", std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+
+        Self(path)
+    }
+}
+
+impl Drop for TempDir
+{
+    fn drop(&mut self)
+    {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// Mbc1 cartridge with 8 KiB of battery backed ram.
+fn cartridge(save_path: &Path) -> Cartridge
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = 0x03;
+    rom[0x149] = 0x02;
+
+    let mut cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .save_path(save_path)
+        .from_bytes(rom)
+        .unwrap();
+    cartridge.write_byte(0x0000, 0x0A);
+
+    cartridge
+}
+
+#[test]
+fn save_replaces_the_file()
+{
+    let dir = TempDir::new("save");
+    let path = dir.0.join("game.sav");
+    fs::write(&path, [0xAA; 0x2000]).unwrap();
+
+    let mut cartridge = cartridge(&path);
+    assert_eq!(cartridge.read_byte(0xA000), 0xAA);
+
+    cartridge.write_byte(0xA000, 0x42);
+    cartridge.save().unwrap();
+
+    let data = fs::read(&path).unwrap();
+    assert_eq!(data.len(), 0x2000);
+    assert_eq!(data[0], 0x42);
+    assert_eq!(data[1], 0xAA);
+
+    // Nothing but the save is left behind.
+    let entries = fs::read_dir(&dir.0).unwrap().count();
+    assert_eq!(entries, 1);
+}
+
+#[test]
+fn autosave_waits_for_the_writes_to_stop()
+{
+    let dir = TempDir::new("autosave");
+    let path = dir.0.join("game.sav");
+
+    let mut cartridge = cartridge(&path);
+    cartridge.write_byte(0xA000, 0x42);
+
+    cartridge.autosave().unwrap();
+    assert!(!path.exists());
+
+    thread::sleep(Duration::from_millis(1100));
+    cartridge.autosave().unwrap();
+    assert_eq!(fs::read(&path).unwrap()[0], 0x42);
+}
+
+// Clock moved forward by the test, the copies share the same time.
+#[derive(Clone)]
+struct FakeClock(Arc<AtomicU64>);
+
+impl FakeClock
+{
+    fn new(now: u64) -> Self
+    {
+        Self(Arc::new(AtomicU64::new(now)))
+    }
+
+    fn advance(&self, seconds: u64)
+    {
+        self.0.fetch_add(seconds, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FakeClock
+{
+    fn now(&self) -> u64
+    {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+// `MBC3` cartridge with a clock and the given ram size code.
+fn rtc_cartridge(cartridge_type: u8, ram_size: u8, save_path: &Path, clock: &FakeClock)
+-> Cartridge
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x147] = cartridge_type;
+    rom[0x149] = ram_size;
+
+    Cartridge::builder()
+        .validation(Validation::Lenient)
+        .save_path(save_path)
+        .clock(Box::new(clock.clone()))
+        .from_bytes(rom)
+        .unwrap()
+}
+
+// Seconds and minutes of the clock after latching.
+fn latch(cartridge: &mut Cartridge) -> [u8; 2]
+{
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x6000, 0x00);
+    cartridge.write_byte(0x6000, 0x01);
+
+    [0x08, 0x09].map(|reg| {
+        cartridge.write_byte(0x4000, reg);
+        cartridge.read_byte(0xA000)
+    })
+}
+
+// Footer with the seconds and minutes set in both the current and the
+// latched registers, followed by the timestamp of the given length.
+fn footer(seconds: u32, minutes: u32, timestamp: &[u8]) -> Vec<u8>
+{
+    let registers = [seconds, minutes, 0, 0, 0];
+    let mut footer: Vec<u8> = registers
+        .iter()
+        .chain(registers.iter())
+        .flat_map(|reg| reg.to_le_bytes())
+        .collect();
+    footer.extend_from_slice(timestamp);
+
+    footer
+}
+
+#[test]
+fn rtc_footer_survives_a_restart()
+{
+    let dir = TempDir::new("rtc-footer");
+    let path = dir.0.join("game.sav");
+    let clock = FakeClock::new(1_000_000);
+
+    let mut cartridge = rtc_cartridge(0x10, 0x02, &path, &clock);
+    clock.advance(100);
+    cartridge.write_byte(0x0000, 0x0A);
+    cartridge.write_byte(0x4000, 0x00);
+    cartridge.write_byte(0xA000, 0x42);
+    cartridge.save().unwrap();
+    drop(cartridge);
+
+    let data = fs::read(&path).unwrap();
+    assert_eq!(data.len(), 0x2000 + 48);
+    assert_eq!(data[0], 0x42);
+    assert_eq!(data[0x2000 + 40..], 1_000_100u64.to_le_bytes());
+
+    // The clock catches up with the time the game was not running.
+    clock.advance(30);
+    let mut cartridge = rtc_cartridge(0x10, 0x02, &path, &clock);
+    assert_eq!(latch(&mut cartridge), [10, 2]);
+
+    cartridge.write_byte(0x4000, 0x00);
+    assert_eq!(cartridge.read_byte(0xA000), 0x42);
+}
+
+#[test]
+fn legacy_rtc_footer_is_loaded()
+{
+    let dir = TempDir::new("rtc-legacy");
+    let path = dir.0.join("game.sav");
+    let clock = FakeClock::new(2_000_000);
+
+    let mut data = vec![0x00; 0x2000];
+    data.extend(footer(5, 1, &(2_000_000u32 - 60).to_le_bytes()));
+    assert_eq!(data.len(), 0x2000 + 44);
+    fs::write(&path, data).unwrap();
+
+    let mut cartridge = rtc_cartridge(0x10, 0x02, &path, &clock);
+    assert_eq!(latch(&mut cartridge), [5, 2]);
+}
+
+#[test]
+fn rtc_footer_without_ram()
+{
+    let dir = TempDir::new("rtc-no-ram");
+    let path = dir.0.join("game.sav");
+    let clock = FakeClock::new(3_000_000);
+    fs::write(&path, footer(0, 7, &(3_000_000u64 - 1).to_le_bytes())).unwrap();
+
+    let mut cartridge = rtc_cartridge(0x0F, 0x00, &path, &clock);
+    assert_eq!(latch(&mut cartridge), [1, 7]);
+
+    // Nothing but the clock changed, the footer is still refreshed.
+    clock.advance(2);
+    cartridge.save().unwrap();
+
+    let data = fs::read(&path).unwrap();
+    assert_eq!(data.len(), 48);
+    assert_eq!(data[40..], 3_000_002u64.to_le_bytes());
+}
@@ -0,0 +1,91 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::{Cartridge, CartridgeError, CartridgeWarning, Header, NINTENDO_LOGO, Validation};
+
+// `MBC0` rom which passes every check.
+fn valid_rom() -> Vec<u8>
+{
+    let mut rom = vec![0x00; 0x8000];
+    rom[0x104..0x134].copy_from_slice(&NINTENDO_LOGO);
+    rom[0x134..0x13A].copy_from_slice(b"TETRIS");
+    fix_checksums(&mut rom);
+
+    rom
+}
+
+fn fix_checksums(rom: &mut [u8])
+{
+    rom[0x14D] = Header::compute_header_checksum(rom);
+    let global_checksum = Header::compute_global_checksum(rom);
+    rom[0x14E..0x150].copy_from_slice(&global_checksum.to_be_bytes());
+}
+
+fn load(rom: Vec<u8>, validation: Validation) -> Result<Cartridge, CartridgeError>
+{
+    Cartridge::builder().validation(validation).from_bytes(rom)
+}
+
+#[test]
+fn valid_rom_passes_strict_validation()
+{
+    let cartridge = load(valid_rom(), Validation::Strict).unwrap();
+
+    assert!(cartridge.validation().is_valid());
+    assert!(cartridge.warnings().is_empty());
+}
+
+#[test]
+fn bad_logo_is_rejected_only_by_strict_validation()
+{
+    let mut rom = valid_rom();
+    rom[0x104] ^= 0xFF;
+    fix_checksums(&mut rom);
+
+    assert!(matches!(
+        load(rom.clone(), Validation::Strict),
+        Err(CartridgeError::Rejected(CartridgeWarning::InvalidLogo))
+    ));
+
+    let cartridge = load(rom, Validation::Lenient).unwrap();
+    assert!(!cartridge.validation().logo_valid);
+    assert_eq!(cartridge.warnings(), [CartridgeWarning::InvalidLogo]);
+}
+
+#[test]
+fn bad_header_checksum_is_rejected_only_by_strict_validation()
+{
+    let mut rom = valid_rom();
+    let computed = rom[0x14D];
+    rom[0x14D] = computed.wrapping_add(1);
+
+    let warning = CartridgeWarning::HeaderChecksum {
+        expected: computed.wrapping_add(1),
+        computed,
+    };
+    assert!(matches!(
+        load(rom.clone(), Validation::Strict),
+        Err(CartridgeError::Rejected(rejected)) if rejected == warning
+    ));
+
+    // The checksum byte is covered by the global checksum as well.
+    let cartridge = load(rom, Validation::Lenient).unwrap();
+    assert!(!cartridge.validation().passes_boot_check());
+    assert_eq!(cartridge.warnings()[0], warning);
+}
+
+#[test]
+fn bad_global_checksum_is_only_a_warning()
+{
+    let mut rom = valid_rom();
+    rom[0x14F] ^= 0xFF;
+
+    // The boot rom never looks at the global checksum.
+    let cartridge = load(rom, Validation::Strict).unwrap();
+    assert!(cartridge.validation().passes_boot_check());
+    assert!(!cartridge.validation().is_valid());
+    assert!(matches!(
+        cartridge.warnings(),
+        [CartridgeWarning::GlobalChecksum { .. }]
+    ));
+}
@@ -0,0 +1,26 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use gb_memory::CartridgeError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ToolError
+{
+    #[error("{0}")]
+    Usage(String),
+
+    #[error("{path}: {source}")]
+    Io
+    {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("{path}: {source}")]
+    Cartridge
+    {
+        path: String,
+        source: CartridgeError,
+    },
+}
@@ -0,0 +1,258 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fs;
+use std::process::ExitCode;
+
+use gb_memory::consts::rom;
+use gb_memory::{CartridgeError, Header, MAX_ROM_BANKS, ROM_BANK_SIZE};
+
+use crate::errors::ToolError;
+use crate::option_value;
+
+#[derive(Debug)]
+pub struct FixOptions
+{
+    checksums: bool,
+    pad: bool,
+    // The value of the open bus is used by default, same as rgbfix.
+    pad_value: u8,
+    output: Option<String>,
+    rom: String,
+}
+
+impl FixOptions
+{
+    pub fn parse(args: &[String]) -> Result<Self, ToolError>
+    {
+        let mut checksums = false;
+        let mut pad = false;
+        let mut pad_value = 0xFF;
+        let mut output = None;
+        let mut rom = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--checksums" => checksums = true,
+                "--pad" => pad = true,
+                "--pad-value" => pad_value = parse_byte(option_value(&mut args, arg)?)?,
+                "--output" => output = Some(option_value(&mut args, arg)?.clone()),
+                _ if arg.starts_with('-') => {
+                    return Err(ToolError::Usage(format!("unknown option '{arg}'")));
+                }
+                _ if rom.is_some() => {
+                    return Err(ToolError::Usage(
+                        "only one rom can be fixed at once".to_string(),
+                    ));
+                }
+                _ => rom = Some(arg.clone()),
+            }
+        }
+        let rom = rom.ok_or_else(|| ToolError::Usage("missing rom".to_string()))?;
+
+        // Asking for nothing means fixing everything.
+        let all = !checksums && !pad;
+
+        Ok(Self {
+            checksums: checksums || all,
+            pad: pad || all,
+            pad_value,
+            output,
+            rom,
+        })
+    }
+}
+
+pub fn run(options: &FixOptions) -> Result<ExitCode, ToolError>
+{
+    let io_error = |path: &str| {
+        let path = path.to_string();
+        move |source| ToolError::Io { path, source }
+    };
+    let mut rom = fs::read(&options.rom).map_err(io_error(&options.rom))?;
+
+    let cartridge_error = |source| ToolError::Cartridge {
+        path: options.rom.clone(),
+        source,
+    };
+    if rom.len() < rom::HEADER_SIZE {
+        return Err(cartridge_error(CartridgeError::Header(
+            "Rom is too short to contain a header.",
+        )));
+    }
+    // The header of MMM01 multicarts is a part of the menu at the end of
+    // the image, padding would move it.
+    let offset = Header::offset(&rom);
+
+    if options.pad {
+        if offset > 0 {
+            return Err(cartridge_error(CartridgeError::Header(
+                "MMM01 multicarts can not be padded, fix only the checksums.",
+            )));
+        }
+        let banks = pad(&mut rom, options.pad_value).map_err(cartridge_error)?;
+        println!(
+            "Padded to {banks} banks ({} KiB).",
+            banks * ROM_BANK_SIZE / 1024
+        );
+    }
+    // The header checksum is a part of the global one, so it has to be
+    // fixed first.
+    if options.checksums {
+        let header_checksum = offset + rom::CHECKSUM;
+        let global_checksum = offset + rom::GLOBAL_CHECKSUM;
+
+        rom[header_checksum] = Header::compute_header_checksum(&rom[offset..]);
+
+        let checksum = Header::compute_image_checksum(&rom);
+        rom[global_checksum..global_checksum + 2].copy_from_slice(&checksum.to_be_bytes());
+
+        println!(
+            "Header checksum set to 0x{:02X}, global checksum set to 0x{checksum:04X}.",
+            rom[header_checksum]
+        );
+    }
+    let output = options.output.as_deref().unwrap_or(&options.rom);
+    fs::write(output, &rom).map_err(io_error(output))?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+// Pads the image to the next size supported by the header and updates
+// the rom size code to match it. Returns the new number of banks.
+fn pad(rom: &mut Vec<u8>, value: u8) -> Result<usize, CartridgeError>
+{
+    let banks = Header::rom_banks_from_len(rom.len());
+
+    if banks > MAX_ROM_BANKS {
+        return Err(CartridgeError::RomTooLarge(rom.len()));
+    }
+    rom.resize(banks * ROM_BANK_SIZE, value);
+    // The code is the power of two of the size in 32 KiB units.
+    rom[rom::ROM_SIZE] = (banks.trailing_zeros() - 1) as u8;
+
+    Ok(banks)
+}
+
+fn parse_byte(value: &str) -> Result<u8, ToolError>
+{
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u8::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| ToolError::Usage(format!("invalid byte value '{value}'")))
+}
+
+// The tool is a binary only, so its tests can not live in the tests
+// directory like the ones of the libraries.
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String>
+    {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_decimal_and_hex_bytes()
+    {
+        assert_eq!(parse_byte("0").unwrap(), 0x00);
+        assert_eq!(parse_byte("255").unwrap(), 0xFF);
+        assert_eq!(parse_byte("0x1f").unwrap(), 0x1F);
+        assert_eq!(parse_byte("0xFF").unwrap(), 0xFF);
+
+        for invalid in ["", "0x", "256", "0x100", "-1", "ff", "0xZZ"] {
+            assert!(
+                matches!(parse_byte(invalid), Err(ToolError::Usage(_))),
+                "'{invalid}'"
+            );
+        }
+    }
+
+    #[test]
+    fn pads_to_the_next_power_of_two()
+    {
+        for (len, banks) in [
+            (0x150, 2),
+            (0x8000, 2),
+            (0x8001, 4),
+            (0x14000, 8),
+            (0x100000, 64),
+        ] {
+            let mut rom = vec![0x00; len];
+            assert_eq!(pad(&mut rom, 0xFF).unwrap(), banks, "len 0x{len:X}");
+
+            assert_eq!(rom.len(), banks * ROM_BANK_SIZE);
+            assert_eq!(rom[rom::ROM_SIZE], banks.trailing_zeros() as u8 - 1);
+            assert_eq!(Header::rom_banks_from_len(rom.len()), banks);
+            // Only the new part of the image is filled.
+            assert_eq!(rom[len - 1], 0x00);
+            assert!(rom[len..].iter().all(|byte| *byte == 0xFF));
+        }
+    }
+
+    #[test]
+    fn does_not_pad_past_the_largest_size()
+    {
+        let mut rom = vec![0x00; MAX_ROM_BANKS * ROM_BANK_SIZE + 1];
+
+        assert!(matches!(
+            pad(&mut rom, 0xFF),
+            Err(CartridgeError::RomTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn fixes_everything_by_default()
+    {
+        let options = FixOptions::parse(&args(&["game.gb"])).unwrap();
+
+        assert!(options.checksums && options.pad);
+        assert_eq!(options.pad_value, 0xFF);
+        assert_eq!(options.output, None);
+        assert_eq!(options.rom, "game.gb");
+    }
+
+    #[test]
+    fn parses_the_options()
+    {
+        let options = FixOptions::parse(&args(&[
+            "--pad",
+            "--pad-value",
+            "0x00",
+            "game.gb",
+            "--output",
+            "fixed.gb",
+        ]))
+        .unwrap();
+
+        assert!(options.pad && !options.checksums);
+        assert_eq!(options.pad_value, 0x00);
+        assert_eq!(options.output.as_deref(), Some("fixed.gb"));
+
+        let options = FixOptions::parse(&args(&["--checksums", "game.gb"])).unwrap();
+        assert!(options.checksums && !options.pad);
+    }
+
+    #[test]
+    fn rejects_invalid_arguments()
+    {
+        for invalid in [
+            &[][..],
+            &["--checksums"],
+            &["--unknown", "game.gb"],
+            &["game.gb", "other.gb"],
+            &["game.gb", "--pad-value"],
+            &["game.gb", "--pad-value", "0x100"],
+            &["game.gb", "--output"],
+        ] {
+            assert!(
+                matches!(FixOptions::parse(&args(invalid)), Err(ToolError::Usage(_))),
+                "{invalid:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fs;
+use std::process::ExitCode;
+
+use gb_memory::{Cartridge, Checksum, DatEntry, Mapper, RomDatabase, Validation};
+
+use crate::errors::ToolError;
+use crate::json::Value;
+use crate::option_value;
+
+#[derive(Debug, Default)]
+pub struct InfoOptions
+{
+    json: bool,
+    dat: Option<String>,
+    roms: Vec<String>,
+}
+
+impl InfoOptions
+{
+    pub fn parse(args: &[String]) -> Result<Self, ToolError>
+    {
+        let mut options = Self::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => options.json = true,
+                "--dat" => options.dat = Some(option_value(&mut args, arg)?.clone()),
+                _ if arg.starts_with('-') => {
+                    return Err(ToolError::Usage(format!("unknown option '{arg}'")));
+                }
+                _ => options.roms.push(arg.clone()),
+            }
+        }
+        match options.roms.is_empty() {
+            true => Err(ToolError::Usage("missing rom".to_string())),
+            false => Ok(options),
+        }
+    }
+}
+
+struct Report
+{
+    path: String,
+    cartridge: Cartridge,
+    dat: Option<DatEntry>,
+}
+
+// Every rom is reported on its own, a broken one does not stop the
+// rest from being printed, but makes the tool exit with a failure.
+pub fn run(options: &InfoOptions) -> Result<ExitCode, ToolError>
+{
+    let database = match &options.dat {
+        Some(path) => {
+            Some(
+                RomDatabase::from_file(path).map_err(|source| ToolError::Cartridge {
+                    path: path.clone(),
+                    source,
+                })?,
+            )
+        }
+        None => None,
+    };
+    let reports: Vec<_> = options
+        .roms
+        .iter()
+        .map(|path| load(path, database.as_ref()))
+        .collect();
+
+    let failed = reports.iter().any(Result::is_err);
+
+    match options.json {
+        true => {
+            let reports = reports
+                .iter()
+                .zip(&options.roms)
+                .map(|(report, path)| match report {
+                    Ok(report) => report_json(report),
+                    Err(err) => Value::Object(vec![
+                        ("path", path.as_str().into()),
+                        ("error", err.to_string().into()),
+                    ]),
+                });
+            println!("{}", Value::Array(reports.collect()));
+        }
+        false => {
+            for (idx, report) in reports.iter().enumerate() {
+                match report {
+                    Ok(report) if idx > 0 => println!("\n{}", report_text(report)),
+                    Ok(report) => println!("{}", report_text(report)),
+                    Err(err) => eprintln!("error: {err}"),
+                }
+            }
+        }
+    }
+    match failed {
+        true => Ok(ExitCode::FAILURE),
+        false => Ok(ExitCode::SUCCESS),
+    }
+}
+
+// The rom is loaded without a save file and with the lenient validation,
+// the problems are exactly what the user wants to see.
+fn load(path: &str, database: Option<&RomDatabase>) -> Result<Report, ToolError>
+{
+    let rom = fs::read(path).map_err(|source| ToolError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+    let cartridge = Cartridge::builder()
+        .validation(Validation::Lenient)
+        .from_bytes(rom)
+        .map_err(|source| ToolError::Cartridge {
+            path: path.to_string(),
+            source,
+        })?;
+    let dat = database.and_then(|database| cartridge.identify(database).cloned());
+
+    Ok(Report {
+        path: path.to_string(),
+        cartridge,
+        dat,
+    })
+}
+
+// Hardware on the cartridge besides the rom. The ram size and the
+// battery may differ from the cartridge type, e.g. on homebrew, so they
+// are taken from the loaded cartridge.
+fn capabilities(cartridge: &Cartridge) -> Vec<&'static str>
+{
+    let cartridge_type = cartridge.header().cartridge_type;
+    let has_ram = cartridge_type.has_ram() || cartridge.header().ram_banks > 0;
+
+    [
+        ("ram", has_ram),
+        ("battery", cartridge.has_battery()),
+        ("rtc", cartridge_type.has_rtc()),
+        ("rumble", cartridge_type.has_rumble()),
+        ("sensor", cartridge_type.has_sensor()),
+        (
+            "infrared",
+            matches!(cartridge.mapper(), Mapper::HuC1 | Mapper::HuC3),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, present)| present.then_some(name))
+    .collect()
+}
+
+fn report_text(report: &Report) -> String
+{
+    let cartridge = &report.cartridge;
+    let hashes = cartridge.hashes();
+
+    let capabilities = match capabilities(cartridge) {
+        capabilities if capabilities.is_empty() => "none".to_string(),
+        capabilities => capabilities.join(", "),
+    };
+    let mut lines = vec![
+        format!("== {} ==", report.path),
+        cartridge.header().to_string(),
+        String::new(),
+        cartridge.validation().to_string(),
+        String::new(),
+        format!("Mapper:            {}", cartridge.mapper()),
+        format!("Capabilities:      {capabilities}"),
+        format!("Size:              {} bytes", hashes.size),
+        format!("CRC32:             {:08X}", hashes.crc32),
+        format!("SHA-1:             {}", hashes.sha1_hex()),
+    ];
+    if let Some(entry) = &report.dat {
+        let region = entry.region.as_deref().unwrap_or("-");
+        let revision = entry.revision.as_deref().unwrap_or("-");
+
+        lines.push(format!("DAT name:          {}", entry.name));
+        lines.push(format!("DAT region:        {region}"));
+        lines.push(format!("DAT revision:      {revision}"));
+    }
+    for warning in cartridge.warnings() {
+        lines.push(format!("Warning:           {warning}"));
+    }
+    lines.join("\n")
+}
+
+fn checksum_json<T>(checksum: &Checksum<T>) -> Value
+where
+    T: Copy + PartialEq + Into<u64>,
+{
+    Value::Object(vec![
+        ("expected", checksum.expected.into().into()),
+        ("computed", checksum.computed.into().into()),
+        ("valid", checksum.is_valid().into()),
+    ])
+}
+
+fn report_json(report: &Report) -> Value
+{
+    let cartridge = &report.cartridge;
+    let header = cartridge.header();
+    let validation = cartridge.validation();
+    let hashes = cartridge.hashes();
+
+    let dat = report.dat.as_ref().map(|entry| {
+        Value::Object(vec![
+            ("name", entry.name.as_str().into()),
+            ("region", entry.region.as_deref().into()),
+            ("revision", entry.revision.as_deref().into()),
+        ])
+    });
+    let capabilities = capabilities(cartridge).into_iter().map(Value::from);
+    let warnings = cartridge
+        .warnings()
+        .iter()
+        .map(|warning| Value::from(warning.to_string()));
+
+    Value::Object(vec![
+        ("path", report.path.as_str().into()),
+        (
+            "header",
+            Value::Object(vec![
+                ("title", header.title.as_str().into()),
+                (
+                    "manufacturer_code",
+                    header.manufacturer_code.as_deref().into(),
+                ),
+                ("licensee", header.licensee.to_string().into()),
+                ("cgb", header.cgb.to_string().into()),
+                ("sgb", header.sgb.to_string().into()),
+                ("cartridge_type", header.cartridge_type.to_string().into()),
+                ("rom_banks", header.rom_banks.into()),
+                ("ram_banks", header.ram_banks.into()),
+                ("destination", header.destination.to_string().into()),
+                ("rom_version", (header.rom_version as u64).into()),
+                ("header_checksum", (header.header_checksum as u64).into()),
+                ("global_checksum", (header.global_checksum as u64).into()),
+            ]),
+        ),
+        (
+            "validation",
+            Value::Object(vec![
+                ("logo_valid", validation.logo_valid.into()),
+                (
+                    "header_checksum",
+                    checksum_json(&validation.header_checksum),
+                ),
+                (
+                    "global_checksum",
+                    checksum_json(&validation.global_checksum),
+                ),
+                ("passes_boot_check", validation.passes_boot_check().into()),
+            ]),
+        ),
+        ("mapper", cartridge.mapper().to_string().into()),
+        ("capabilities", Value::Array(capabilities.collect())),
+        (
+            "hashes",
+            Value::Object(vec![
+                ("size", hashes.size.into()),
+                ("crc32", format!("{:08x}", hashes.crc32).into()),
+                ("sha1", hashes.sha1_hex().into()),
+            ]),
+        ),
+        ("dat", dat.into()),
+        ("warnings", Value::Array(warnings.collect())),
+    ])
+}
@@ -0,0 +1,135 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+use std::fmt::{self, Write};
+
+// Just enough of JSON to print the reports, the keys are kept in the
+// order they were added.
+pub enum Value
+{
+    Null,
+    Bool(bool),
+    Number(u64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(&'static str, Value)>),
+}
+
+impl Value
+{
+    fn write(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result
+    {
+        let pad = "  ".repeat(indent + 1);
+
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(val) => write!(f, "{val}"),
+            Self::Number(val) => write!(f, "{val}"),
+            Self::String(val) => write_string(f, val),
+            Self::Array(items) if items.is_empty() => f.write_str("[]"),
+            Self::Array(items) => {
+                f.write_str("[\n")?;
+
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(",\n")?;
+                    }
+                    f.write_str(&pad)?;
+                    item.write(f, indent + 1)?;
+                }
+                write!(f, "\n{}]", "  ".repeat(indent))
+            }
+            Self::Object(fields) if fields.is_empty() => f.write_str("{}"),
+            Self::Object(fields) => {
+                f.write_str("{\n")?;
+
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(",\n")?;
+                    }
+                    f.write_str(&pad)?;
+                    write_string(f, key)?;
+                    f.write_str(": ")?;
+                    value.write(f, indent + 1)?;
+                }
+                write!(f, "\n{}}}", "  ".repeat(indent))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Value
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.write(f, 0)
+    }
+}
+
+impl From<bool> for Value
+{
+    fn from(val: bool) -> Self
+    {
+        Self::Bool(val)
+    }
+}
+
+impl From<u64> for Value
+{
+    fn from(val: u64) -> Self
+    {
+        Self::Number(val)
+    }
+}
+
+impl From<usize> for Value
+{
+    fn from(val: usize) -> Self
+    {
+        Self::Number(val as u64)
+    }
+}
+
+impl From<String> for Value
+{
+    fn from(val: String) -> Self
+    {
+        Self::String(val)
+    }
+}
+
+impl From<&str> for Value
+{
+    fn from(val: &str) -> Self
+    {
+        Self::String(val.to_string())
+    }
+}
+
+impl<T> From<Option<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(val: Option<T>) -> Self
+    {
+        val.map_or(Self::Null, Into::into)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, val: &str) -> fmt::Result
+{
+    f.write_char('"')?;
+
+    for c in val.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
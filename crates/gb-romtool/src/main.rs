@@ -0,0 +1,68 @@
+// Copyright: (c) 2025, Wiktor Nowak
+// GNU General Public License v3.0 (see LICENSE.md or https://www.gnu.org/licenses/gpl-3.0.txt)
+
+mod errors;
+mod fix;
+mod info;
+mod json;
+
+use std::env;
+use std::process::ExitCode;
+
+use crate::errors::ToolError;
+use crate::fix::FixOptions;
+use crate::info::InfoOptions;
+
+const USAGE: &str = "\
+Usage:
+    gb-romtool info [--json] [--dat <file>] <rom>...
+    gb-romtool fix [--checksums] [--pad] [--pad-value <byte>] [--output <file>] <rom>
+
+Commands:
+    info    Prints the header, the validation results, the capabilities
+            of the cartridge and the hashes of every rom.
+    fix     Rewrites the header and global checksums and pads the rom
+            to the next valid size. Without --checksums or --pad both
+            are applied. The rom is modified in place unless --output
+            is given.
+";
+
+fn main() -> ExitCode
+{
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(code) => code,
+        Err(ToolError::Usage(message)) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, ToolError>
+{
+    match args.split_first() {
+        Some((command, rest)) if command == "info" => info::run(&InfoOptions::parse(rest)?),
+        Some((command, rest)) if command == "fix" => fix::run(&FixOptions::parse(rest)?),
+        Some((command, _)) if command == "-h" || command == "--help" => {
+            print!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
+        }
+        Some((command, _)) => Err(ToolError::Usage(format!("unknown command '{command}'"))),
+        None => Err(ToolError::Usage("missing command".to_string())),
+    }
+}
+
+// Returns the value of the option, which is always the next argument.
+fn option_value<'a, I>(args: &mut I, option: &str) -> Result<&'a String, ToolError>
+where
+    I: Iterator<Item = &'a String>,
+{
+    args.next()
+        .ok_or_else(|| ToolError::Usage(format!("missing value for '{option}'")))
+}